@@ -0,0 +1,89 @@
+use crate::{Blend, Color, Image, Rect};
+
+/// A surface of pixels that can be drawn on.
+///
+/// This is implemented by [`Image`], and can be implemented by surfaces that
+/// are not created by the MiniLibX, so that they are drawn the same way.
+pub trait Canvas {
+    fn width(&self) -> u32;
+    fn height(&self) -> u32;
+
+    /// Returns the color of the pixel at (`x`, `y`), or [`None`] if it is
+    /// outside the canvas.
+    fn get_pixel(&self, x: u32, y: u32) -> Option<Color>;
+
+    /// Sets the color of the pixel at (`x`, `y`).
+    ///
+    /// ## Panics
+    ///
+    /// This function panics if the pixel is outside the canvas.
+    fn set_pixel(&mut self, x: u32, y: u32, color: Color);
+
+    /// Fills `rect` with `color`. The parts of `rect` outside the canvas are
+    /// ignored.
+    fn fill_rect(&mut self, rect: Rect, color: Color) {
+        let rect = rect.clip(self.width(), self.height());
+        for y in rect.y..rect.y + rect.height {
+            for x in rect.x..rect.x + rect.width {
+                self.set_pixel(x, y, color);
+            }
+        }
+    }
+
+    /// Draws the area `src` of `source` at (`x`, `y`) of this canvas,
+    /// combining the pixels according to `blend`.
+    ///
+    /// The parts of `src` outside `source`, and the pixels that would be drawn
+    /// outside this canvas, are ignored.
+    fn blit<S: Canvas>(&mut self, source: &S, src: Rect, x: i32, y: i32, blend: Blend)
+    where
+        Self: Sized,
+    {
+        let src = src.clip(source.width(), source.height());
+        for j in 0..src.height {
+            let dst_y = y + j as i32;
+            if dst_y < 0 || dst_y >= self.height() as i32 {
+                continue;
+            }
+
+            for i in 0..src.width {
+                let dst_x = x + i as i32;
+                if dst_x < 0 || dst_x >= self.width() as i32 {
+                    continue;
+                }
+
+                let (dst_x, dst_y) = (dst_x as u32, dst_y as u32);
+                let color = source.get_pixel(src.x + i, src.y + j).unwrap();
+                let color = match blend {
+                    Blend::Replace => color,
+                    Blend::Alpha => color.over(self.get_pixel(dst_x, dst_y).unwrap()),
+                    Blend::ColorKey(key) if color == key => continue,
+                    Blend::ColorKey(_) => color,
+                };
+                self.set_pixel(dst_x, dst_y, color);
+            }
+        }
+    }
+}
+
+impl Canvas for Image<'_> {
+    #[inline]
+    fn width(&self) -> u32 {
+        Image::width(self)
+    }
+
+    #[inline]
+    fn height(&self) -> u32 {
+        Image::height(self)
+    }
+
+    #[inline]
+    fn get_pixel(&self, x: u32, y: u32) -> Option<Color> {
+        Image::get_pixel(self, x, y)
+    }
+
+    #[inline]
+    fn set_pixel(&mut self, x: u32, y: u32, color: Color) {
+        Image::set_pixel(self, x, y, color)
+    }
+}
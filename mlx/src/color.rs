@@ -0,0 +1,80 @@
+/// A color, with an alpha channel.
+///
+/// An alpha of 255 is opaque, and an alpha of 0 is fully transparent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Color {
+    pub const BLACK: Self = Self::rgb(0x000000);
+    pub const WHITE: Self = Self::rgb(0xffffff);
+    pub const TRANSPARENT: Self = Self::rgba(0, 0, 0, 0);
+
+    /// Creates an opaque color from its `0xRRGGBB` value.
+    #[inline]
+    pub const fn rgb(value: u32) -> Self {
+        Self {
+            r: (value >> 16) as u8,
+            g: (value >> 8) as u8,
+            b: value as u8,
+            a: 255,
+        }
+    }
+
+    #[inline]
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Returns whether the color is fully opaque.
+    #[inline]
+    pub fn is_opaque(self) -> bool {
+        self.a == 255
+    }
+
+    /// Mixes `self` with `other`, `t` being the weight of `other`, from 0 to 1.
+    pub fn mix(self, other: Self, t: f32) -> Self {
+        let channel = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        Self {
+            r: channel(self.r, other.r),
+            g: channel(self.g, other.g),
+            b: channel(self.b, other.b),
+            a: channel(self.a, other.a),
+        }
+    }
+
+    /// Draws `self` over `background`, according to the alpha of `self`.
+    ///
+    /// The result has the alpha of `background`.
+    pub fn over(self, background: Self) -> Self {
+        Self {
+            a: background.a,
+            ..background.mix(self, self.a as f32 / 255.0)
+        }
+    }
+
+    /// Converts the color to the value of a pixel of a MiniLibX image.
+    ///
+    /// The highest byte is the transparency of the pixel rather than its
+    /// opacity, so that `0x00RRGGBB` is opaque.
+    #[inline]
+    pub(crate) fn to_pixel(self) -> u32 {
+        u32::from_be_bytes([255 - self.a, self.r, self.g, self.b])
+    }
+
+    /// Converts the value of a pixel of a MiniLibX image to a color.
+    #[inline]
+    pub(crate) fn from_pixel(value: u32) -> Self {
+        let [t, r, g, b] = value.to_be_bytes();
+        Self {
+            r,
+            g,
+            b,
+            a: 255 - t,
+        }
+    }
+}
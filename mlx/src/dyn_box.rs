@@ -12,7 +12,7 @@ impl<'a> DynBox<'a> {
     /// Creates a new [`DynBox`] from the provided box.
     pub fn new<T: 'a>(b: Box<T>) -> Self {
         unsafe fn drop<T>(data: *mut ()) {
-            Box::from_raw(data as *mut T);
+            std::mem::drop(Box::from_raw(data as *mut T));
         }
 
         Self {
@@ -0,0 +1,104 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+use crate::*;
+
+/// An event that occurred on a [`Window`].
+///
+/// Each variant corresponds to the [`Hook`] of the same name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    KeyPress(KeyCode),
+    KeyRelease(KeyCode),
+    MousePress { button: u32, x: i32, y: i32 },
+    MouseRelease { button: u32, x: i32, y: i32 },
+    MouseMove { x: i32, y: i32 },
+    MouseEnter,
+    MouseLeave,
+    FocusIn,
+    FocusOut,
+    Expose,
+    Visibility,
+    Configure,
+    Destroy,
+}
+
+/// The events that occurred on a [`Window`] and were not polled yet.
+type Queue = Rc<RefCell<VecDeque<Event>>>;
+
+/// Collects every event of a [`Window`] into a queue, so that they can be
+/// handled one after the other from the body of [`Mlx::start_loop`].
+///
+/// ```ignore
+/// let mut events = Events::new(&win);
+/// mlx.start_loop(|| {
+///     while let Some(event) = events.poll() {
+///         match event {
+///             Event::Destroy => mlx.stop_loop(),
+///             _ => (),
+///         }
+///     }
+/// });
+/// ```
+///
+/// The events are hooked to the window until this instance is dropped. As only
+/// one function can be hooked to a given event, this replaces any function
+/// previously hooked with [`Window::hook`].
+pub struct Events<'a> {
+    queue: Queue,
+    _hooks: Vec<HookGuard<'a>>,
+}
+
+/// Hooks a function that pushes the events `H` to `queue`, once converted
+/// with `to_event`.
+fn forward<'a, H>(window: &'a Window, queue: &Queue, to_event: fn(H) -> Event) -> HookGuard<'a>
+where
+    H: Hook + 'static,
+{
+    let queue = queue.clone();
+    window.hook(move |hook: H| queue.borrow_mut().push_back(to_event(hook)))
+}
+
+impl<'a> Events<'a> {
+    /// Starts collecting the events of `window`.
+    pub fn new(window: &'a Window) -> Self {
+        let queue = Queue::default();
+        let hooks = vec![
+            forward(window, &queue, |KeyPress(keycode)| Event::KeyPress(keycode)),
+            forward(window, &queue, |KeyRelease(keycode)| {
+                Event::KeyRelease(keycode)
+            }),
+            forward(window, &queue, |MousePress { button, x, y }| {
+                Event::MousePress { button, x, y }
+            }),
+            forward(window, &queue, |MouseRelease { button, x, y }| {
+                Event::MouseRelease { button, x, y }
+            }),
+            forward(window, &queue, |MouseMove { x, y }| Event::MouseMove {
+                x,
+                y,
+            }),
+            forward(window, &queue, |MouseEnter| Event::MouseEnter),
+            forward(window, &queue, |MouseLeave| Event::MouseLeave),
+            forward(window, &queue, |FocusIn| Event::FocusIn),
+            forward(window, &queue, |FocusOut| Event::FocusOut),
+            forward(window, &queue, |Expose| Event::Expose),
+            forward(window, &queue, |Visibility| Event::Visibility),
+            forward(window, &queue, |Configure| Event::Configure),
+            forward(window, &queue, |Destroy| Event::Destroy),
+        ];
+
+        Self {
+            queue,
+            _hooks: hooks,
+        }
+    }
+
+    /// Removes the oldest event from the queue and returns it, or `None` if
+    /// no event is left.
+    #[inline]
+    pub fn poll(&mut self) -> Option<Event> {
+        self.queue.borrow_mut().pop_front()
+    }
+}
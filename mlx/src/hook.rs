@@ -34,7 +34,7 @@ impl Hook for KeyPress {
             (&mut *(userdata as *mut F))(KeyPress(KeyCode(keycode as u32)));
             0
         }
-        callback::<F> as usize
+        callback::<F> as *const () as usize
     }
 }
 
@@ -57,7 +57,7 @@ impl Hook for KeyRelease {
             (&mut *(userdata as *mut F))(KeyRelease(KeyCode(keycode as u32)));
             0
         }
-        callback::<F> as usize
+        callback::<F> as *const () as usize
     }
 }
 
@@ -86,14 +86,10 @@ impl Hook for MousePress {
         where
             F: FnMut(MousePress),
         {
-            (&mut *(userdata as *mut F))(MousePress {
-                button: button as u32,
-                x: x as i32,
-                y: y as i32,
-            });
+            (&mut *(userdata as *mut F))(MousePress { button, x, y });
             0
         }
-        callback::<F> as usize
+        callback::<F> as *const () as usize
     }
 }
 
@@ -122,18 +118,40 @@ impl Hook for MouseRelease {
         where
             F: FnMut(MouseRelease),
         {
-            (&mut *(userdata as *mut F))(MouseRelease {
-                button: button as u32,
-                x: x as i32,
-                y: y as i32,
-            });
+            (&mut *(userdata as *mut F))(MouseRelease { button, x, y });
             0
         }
-        callback::<F> as usize
+        callback::<F> as *const () as usize
     }
 }
 
-/// Fires whenever a mouse button is released.
+/// Fires whenever the mouse moves within the window.
+#[derive(Debug, Clone, Copy)]
+pub struct MouseMove {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Hook for MouseMove {
+    const X_EVENT: c_int = 6;
+    const X_MASK: c_int = 1 << 6;
+
+    fn get_callback<F>() -> usize
+    where
+        F: FnMut(Self),
+    {
+        unsafe extern "C" fn callback<F>(x: c_int, y: c_int, userdata: *mut c_void) -> c_int
+        where
+            F: FnMut(MouseMove),
+        {
+            (&mut *(userdata as *mut F))(MouseMove { x, y });
+            0
+        }
+        callback::<F> as *const () as usize
+    }
+}
+
+/// Fires whenever the window is closed.
 #[derive(Debug, Clone, Copy)]
 pub struct Destroy;
 
@@ -152,6 +170,92 @@ impl Hook for Destroy {
             (&mut *(userdata as *mut F))(Destroy);
             0
         }
-        callback::<F> as usize
+        callback::<F> as *const () as usize
     }
 }
+
+/// Implements [`Hook`] for an event that MiniLibX reports without any
+/// parameter.
+macro_rules! parameterless_hook {
+    ($(#[$attr:meta])* $name:ident, $x_event:expr, $x_mask:expr) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name;
+
+        impl Hook for $name {
+            const X_EVENT: c_int = $x_event;
+            const X_MASK: c_int = $x_mask;
+
+            fn get_callback<F>() -> usize
+            where
+                F: FnMut(Self),
+            {
+                unsafe extern "C" fn callback<F>(userdata: *mut c_void) -> c_int
+                where
+                    F: FnMut($name),
+                {
+                    (&mut *(userdata as *mut F))($name);
+                    0
+                }
+                callback::<F> as *const () as usize
+            }
+        }
+    };
+}
+
+/// The name X11 gives to [`MouseMove`].
+pub type MotionNotify = MouseMove;
+
+parameterless_hook!(
+    /// Fires whenever a part of the window must be redrawn, such as when it
+    /// was covered by another window.
+    Expose,
+    12,
+    1 << 15
+);
+
+parameterless_hook!(
+    /// Fires whenever the mouse enters the window.
+    MouseEnter,
+    7,
+    1 << 4
+);
+
+parameterless_hook!(
+    /// Fires whenever the mouse leaves the window.
+    MouseLeave,
+    8,
+    1 << 5
+);
+
+parameterless_hook!(
+    /// Fires whenever the window gets the keyboard focus.
+    FocusIn,
+    9,
+    1 << 21
+);
+
+parameterless_hook!(
+    /// Fires whenever the window loses the keyboard focus.
+    FocusOut,
+    10,
+    1 << 21
+);
+
+parameterless_hook!(
+    /// Fires whenever the window becomes more or less visible, such as when it
+    /// is covered by another window or minimized.
+    Visibility,
+    15,
+    1 << 16
+);
+
+parameterless_hook!(
+    /// Fires whenever the window is moved or resized. [`Window::size`] returns
+    /// its new size.
+    ///
+    /// [`Window::size`]: crate::Window::size
+    Configure,
+    22,
+    1 << 17
+);
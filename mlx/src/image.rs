@@ -1,15 +1,82 @@
 use std::ffi::CStr;
-use std::os::raw::c_int;
+use std::os::raw::{c_char, c_int};
 
-use crate::Mlx;
+use crate::{Canvas, Color, Mlx};
 
 /// An error that might occur when creating an [`Image`].
 #[derive(Debug, Clone, Copy)]
 pub struct ImageError;
 
+/// An area of an image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Rect {
+    #[inline]
+    pub const fn new(x: u32, y: u32, width: u32, height: u32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Returns the smallest area containing both `self` and `other`.
+    pub fn union(self, other: Self) -> Self {
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        let right = (self.x + self.width).max(other.x + other.width);
+        let bottom = (self.y + self.height).max(other.y + other.height);
+        Self::new(x, y, right - x, bottom - y)
+    }
+
+    /// Returns the area covered by both `self` and `other`, if any.
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let right = (self.x + self.width).min(other.x + other.width);
+        let bottom = (self.y + self.height).min(other.y + other.height);
+        (x < right && y < bottom).then(|| Self::new(x, y, right - x, bottom - y))
+    }
+
+    /// Returns the part of `self` that is inside an image of `width` by
+    /// `height` pixels.
+    pub(crate) fn clip(self, width: u32, height: u32) -> Self {
+        let x = self.x.min(width);
+        let y = self.y.min(height);
+        Self {
+            x,
+            y,
+            width: self.width.min(width - x),
+            height: self.height.min(height - y),
+        }
+    }
+}
+
+/// How the pixels of an image are combined with the pixels they are drawn
+/// over by [`Image::blit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Blend {
+    /// The pixels are copied as is.
+    Replace,
+    /// The pixels are drawn over the destination according to their alpha.
+    Alpha,
+    /// The pixels are copied, except those of the provided color.
+    ColorKey(Color),
+}
+
 /// A loaded image.
-pub struct Image {
-    mlx: crate::raw::Mlx,
+///
+/// An image borrows the [`Mlx`] instance it was created with, which ensures
+/// that it is destroyed first.
+pub struct Image<'mlx> {
+    mlx: &'mlx Mlx,
     handle: crate::raw::Image,
 
     width: u32,
@@ -20,72 +87,67 @@ pub struct Image {
     data: *mut u8,
 }
 
-impl Image {
-    pub(crate) unsafe fn create(
-        mlx: crate::raw::Mlx,
-        width: u32,
-        height: u32,
-    ) -> Result<Self, ImageError> {
-        let handle = crate::raw::mlx_new_image(mlx, width as c_int, height as c_int);
-
-        let mut bits_per_pixel = 0;
-        let mut line_size = 0;
-        let mut endian = 0;
-
-        let data =
-            crate::raw::mlx_get_data_addr(handle, &mut bits_per_pixel, &mut line_size, &mut endian);
+impl<'mlx> Image<'mlx> {
+    pub(crate) fn create(mlx: &'mlx Mlx, width: u32, height: u32) -> Result<Self, ImageError> {
+        // Safety:
+        //  The `Mlx` handle is valid for as long as `mlx` is borrowed.
+        let handle =
+            unsafe { crate::raw::mlx_new_image(mlx.as_raw(), width as c_int, height as c_int) };
 
         if handle.is_null() {
             Err(ImageError)
         } else {
-            Ok(Self {
-                mlx,
-                handle,
-                width,
-                height,
-                big_endian: endian != 0,
-                bytes_per_pixel: bits_per_pixel as u32 / 8,
-                line_size: line_size as u32,
-                data: data as *mut u8,
-            })
+            Ok(unsafe { Self::image_finish(mlx, handle, width, height) })
         }
     }
 
-    pub(crate) unsafe fn create_from_xpm(
-        mlx: crate::raw::Mlx,
-        xpmdata: &CStr,
-    ) -> Result<Self, ImageError> {
+    pub(crate) fn create_from_xpm(mlx: &'mlx Mlx, xpm_data: &[&CStr]) -> Result<Self, ImageError> {
         let mut width = 0;
         let mut height = 0;
 
-        let handle = crate::raw::mlx_xpm_to_image(mlx, xpmdata.as_ptr(), &mut width, &mut height);
+        let lines: Vec<*const c_char> = xpm_data.iter().map(|line| line.as_ptr()).collect();
+        // Safety:
+        //  Every line is a valid C string, which outlives the call.
+        let handle = unsafe {
+            crate::raw::mlx_xpm_to_image(mlx.as_raw(), lines.as_ptr(), &mut width, &mut height)
+        };
 
         if handle.is_null() {
             Err(ImageError)
         } else {
-            Ok(Self::image_finish(mlx, handle, width as u32, height as u32))
+            Ok(unsafe { Self::image_finish(mlx, handle, width as u32, height as u32) })
         }
     }
 
-    pub(crate) unsafe fn create_from_xpm_file(
-        mlx: crate::raw::Mlx,
+    pub(crate) fn create_from_xpm_file(
+        mlx: &'mlx Mlx,
         filename: &CStr,
     ) -> Result<Self, ImageError> {
         let mut width = 0;
         let mut height = 0;
 
-        let handle =
-            crate::raw::mlx_xpm_file_to_image(mlx, filename.as_ptr(), &mut width, &mut height);
+        let handle = unsafe {
+            crate::raw::mlx_xpm_file_to_image(
+                mlx.as_raw(),
+                filename.as_ptr(),
+                &mut width,
+                &mut height,
+            )
+        };
 
         if handle.is_null() {
             Err(ImageError)
         } else {
-            Ok(Self::image_finish(mlx, handle, width as u32, height as u32))
+            Ok(unsafe { Self::image_finish(mlx, handle, width as u32, height as u32) })
         }
     }
 
+    /// ## Safety
+    ///
+    /// `handle` must be a valid image created with `mlx`, of `width` by
+    /// `height` pixels.
     unsafe fn image_finish(
-        mlx: crate::raw::Mlx,
+        mlx: &'mlx Mlx,
         handle: crate::raw::Image,
         width: u32,
         height: u32,
@@ -100,8 +162,8 @@ impl Image {
         Self {
             mlx,
             handle,
-            width: width as u32,
-            height: height as u32,
+            width,
+            height,
             big_endian: endian != 0,
             bytes_per_pixel: bits_per_pixel as u32 / 8,
             line_size: line_size as u32,
@@ -111,8 +173,8 @@ impl Image {
 
     /// Returns a reference to the [`Mlx`] instance associated with this [`Image`].
     #[inline]
-    pub fn mlx(&self) -> &Mlx {
-        Mlx::wrap_ref(&self.mlx)
+    pub fn mlx(&self) -> &'mlx Mlx {
+        self.mlx
     }
 
     /// Returns the handle protected by this [`Image`] instance.
@@ -154,12 +216,134 @@ impl Image {
     pub fn data(&self) -> *mut u8 {
         self.data
     }
+
+    /// Returns the byte offset of the pixel at (`x`, `y`), if it is inside the
+    /// image.
+    #[inline]
+    fn offset(&self, x: u32, y: u32) -> Option<usize> {
+        if x < self.width && y < self.height {
+            Some((y * self.line_size + x * self.bytes_per_pixel) as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the color of the pixel at (`x`, `y`), or [`None`] if it is
+    /// outside the image.
+    pub fn get_pixel(&self, x: u32, y: u32) -> Option<Color> {
+        let offset = self.offset(x, y)?;
+        let len = self.bytes_per_pixel.min(4) as usize;
+        let bytes = &self.as_bytes()[offset..offset + len];
+
+        let mut value = [0u8; 4];
+        if self.big_endian {
+            value[4 - len..].copy_from_slice(bytes);
+        } else {
+            value[..len].copy_from_slice(bytes);
+            value.reverse();
+        }
+        let mut color = Color::from_pixel(u32::from_be_bytes(value));
+        // Without a fourth byte, there is no transparency.
+        if len < 4 {
+            color.a = 255;
+        }
+        Some(color)
+    }
+
+    /// Sets the color of the pixel at (`x`, `y`).
+    ///
+    /// ## Panics
+    ///
+    /// This function panics if the pixel is outside the image.
+    pub fn set_pixel(&mut self, x: u32, y: u32, color: Color) {
+        let offset = self.offset(x, y).unwrap_or_else(|| {
+            panic!(
+                "pixel ({}, {}) is outside of a {}x{} image",
+                x, y, self.width, self.height
+            )
+        });
+        let len = self.bytes_per_pixel.min(4) as usize;
+        let big_endian = self.big_endian;
+
+        let value = color.to_pixel().to_be_bytes();
+        let bytes = &mut self.as_bytes_mut()[offset..offset + len];
+        if big_endian {
+            bytes.copy_from_slice(&value[4 - len..]);
+        } else {
+            for (byte, &v) in bytes.iter_mut().zip(value.iter().rev()) {
+                *byte = v;
+            }
+        }
+    }
+
+    /// Returns the bytes of the pixels of the line `y`, without the padding
+    /// at the end of the line.
+    ///
+    /// ## Panics
+    ///
+    /// This function panics if `y` is outside the image.
+    pub fn row(&self, y: u32) -> &[u8] {
+        assert!(y < self.height, "row {} is outside of the image", y);
+        let start = (y * self.line_size) as usize;
+        &self.as_bytes()[start..start + (self.width * self.bytes_per_pixel) as usize]
+    }
+
+    /// Returns the bytes of the pixels of the line `y`, without the padding
+    /// at the end of the line.
+    ///
+    /// ## Panics
+    ///
+    /// This function panics if `y` is outside the image.
+    pub fn row_mut(&mut self, y: u32) -> &mut [u8] {
+        assert!(y < self.height, "row {} is outside of the image", y);
+        let start = (y * self.line_size) as usize;
+        let len = (self.width * self.bytes_per_pixel) as usize;
+        &mut self.as_bytes_mut()[start..start + len]
+    }
+
+    /// Fills `rect` with `color`. The parts of `rect` outside the image are
+    /// ignored.
+    #[inline]
+    pub fn fill_rect(&mut self, rect: Rect, color: Color) {
+        Canvas::fill_rect(self, rect, color)
+    }
+
+    /// Draws the area `src` of `source` at (`x`, `y`) of this image, combining
+    /// the pixels according to `blend`.
+    ///
+    /// The parts of `src` outside `source`, and the pixels that would be drawn
+    /// outside this image, are ignored.
+    #[inline]
+    pub fn blit(&mut self, source: &Image, src: Rect, x: i32, y: i32, blend: Blend) {
+        Canvas::blit(self, source, src, x, y, blend)
+    }
+
+    /// Returns the bytes of the pixels, `line_size` bytes per line.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        // Safety:
+        //  The buffer is owned by the image and has `height` lines.
+        unsafe { std::slice::from_raw_parts(self.data, (self.line_size * self.height) as usize) }
+    }
+
+    /// Returns the bytes of the pixels, `line_size` bytes per line.
+    #[inline]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        // Safety:
+        //  The buffer is owned by the image and has `height` lines.
+        unsafe {
+            std::slice::from_raw_parts_mut(self.data, (self.line_size * self.height) as usize)
+        }
+    }
 }
 
-impl Drop for Image {
+impl Drop for Image<'_> {
     fn drop(&mut self) {
+        // Safety:
+        //  The handle is not used anymore, and no slice of the pixels
+        //  outlives the image.
         unsafe {
-            crate::raw::mlx_destroy_image(self.mlx, self.handle);
+            crate::raw::mlx_destroy_image(self.mlx.as_raw(), self.handle);
         }
     }
 }
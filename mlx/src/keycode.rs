@@ -1,4 +1,9 @@
-/// A keycode.
+use crate::keysyms::LEGACY_KEYSYMS;
+
+/// A keycode, which is the X11 keysym of the key.
+///
+/// Keysyms of printable characters can be converted with
+/// [`KeyCode::to_char`].
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 pub struct KeyCode(pub u32);
 
@@ -26,6 +31,7 @@ macro_rules! define_keycodes {
 }
 
 define_keycodes! {
+    // Latin letters, as typed without Shift.
     const A = 97;
     const B = 98;
     const C = 99;
@@ -53,7 +59,191 @@ define_keycodes! {
     const Y = 121;
     const Z = 122;
 
-    const ESCAPE = 65307;
-    const BACKSPACE = 65288;
-    const RETURN = 65293;
+    // Latin letters, as typed with Shift or Caps Lock.
+    const UPPER_A = 65;
+    const UPPER_B = 66;
+    const UPPER_C = 67;
+    const UPPER_D = 68;
+    const UPPER_E = 69;
+    const UPPER_F = 70;
+    const UPPER_G = 71;
+    const UPPER_H = 72;
+    const UPPER_I = 73;
+    const UPPER_J = 74;
+    const UPPER_K = 75;
+    const UPPER_L = 76;
+    const UPPER_M = 77;
+    const UPPER_N = 78;
+    const UPPER_O = 79;
+    const UPPER_P = 80;
+    const UPPER_Q = 81;
+    const UPPER_R = 82;
+    const UPPER_S = 83;
+    const UPPER_T = 84;
+    const UPPER_U = 85;
+    const UPPER_V = 86;
+    const UPPER_W = 87;
+    const UPPER_X = 88;
+    const UPPER_Y = 89;
+    const UPPER_Z = 90;
+
+    // Digits.
+    const DIGIT_0 = 48;
+    const DIGIT_1 = 49;
+    const DIGIT_2 = 50;
+    const DIGIT_3 = 51;
+    const DIGIT_4 = 52;
+    const DIGIT_5 = 53;
+    const DIGIT_6 = 54;
+    const DIGIT_7 = 55;
+    const DIGIT_8 = 56;
+    const DIGIT_9 = 57;
+
+    // Editing keys.
+    const SPACE = 32;
+    const BACKSPACE = 0xff08;
+    const TAB = 0xff09;
+    const RETURN = 0xff0d;
+    const PAUSE = 0xff13;
+    const SCROLL_LOCK = 0xff14;
+    const ESCAPE = 0xff1b;
+    const DELETE = 0xffff;
+    const INSERT = 0xff63;
+    const MENU = 0xff67;
+
+    // Navigation keys.
+    const HOME = 0xff50;
+    const LEFT = 0xff51;
+    const UP = 0xff52;
+    const RIGHT = 0xff53;
+    const DOWN = 0xff54;
+    const PAGE_UP = 0xff55;
+    const PAGE_DOWN = 0xff56;
+    const END = 0xff57;
+
+    // Function keys.
+    const F1 = 0xffbe;
+    const F2 = 0xffbf;
+    const F3 = 0xffc0;
+    const F4 = 0xffc1;
+    const F5 = 0xffc2;
+    const F6 = 0xffc3;
+    const F7 = 0xffc4;
+    const F8 = 0xffc5;
+    const F9 = 0xffc6;
+    const F10 = 0xffc7;
+    const F11 = 0xffc8;
+    const F12 = 0xffc9;
+
+    // Modifiers.
+    const SHIFT_L = 0xffe1;
+    const SHIFT_R = 0xffe2;
+    const CONTROL_L = 0xffe3;
+    const CONTROL_R = 0xffe4;
+    const CAPS_LOCK = 0xffe5;
+    const SHIFT_LOCK = 0xffe6;
+    const META_L = 0xffe7;
+    const META_R = 0xffe8;
+    const ALT_L = 0xffe9;
+    const ALT_R = 0xffea;
+    const SUPER_L = 0xffeb;
+    const SUPER_R = 0xffec;
+    const HYPER_L = 0xffed;
+    const HYPER_R = 0xffee;
+    const ALT_GR = 0xfe03;
+    const MODE_SWITCH = 0xff7e;
+    const NUM_LOCK = 0xff7f;
+
+    // Keypad.
+    const KP_SPACE = 0xff80;
+    const KP_TAB = 0xff89;
+    const KP_ENTER = 0xff8d;
+    const KP_HOME = 0xff95;
+    const KP_LEFT = 0xff96;
+    const KP_UP = 0xff97;
+    const KP_RIGHT = 0xff98;
+    const KP_DOWN = 0xff99;
+    const KP_PAGE_UP = 0xff9a;
+    const KP_PAGE_DOWN = 0xff9b;
+    const KP_END = 0xff9c;
+    const KP_BEGIN = 0xff9d;
+    const KP_INSERT = 0xff9e;
+    const KP_DELETE = 0xff9f;
+    const KP_MULTIPLY = 0xffaa;
+    const KP_ADD = 0xffab;
+    const KP_SEPARATOR = 0xffac;
+    const KP_SUBTRACT = 0xffad;
+    const KP_DECIMAL = 0xffae;
+    const KP_DIVIDE = 0xffaf;
+    const KP_0 = 0xffb0;
+    const KP_1 = 0xffb1;
+    const KP_2 = 0xffb2;
+    const KP_3 = 0xffb3;
+    const KP_4 = 0xffb4;
+    const KP_5 = 0xffb5;
+    const KP_6 = 0xffb6;
+    const KP_7 = 0xffb7;
+    const KP_8 = 0xffb8;
+    const KP_9 = 0xffb9;
+    const KP_EQUAL = 0xffbd;
+
+    // Dead keys, which add an accent to the next letter.
+    const DEAD_GRAVE = 0xfe50;
+    const DEAD_ACUTE = 0xfe51;
+    const DEAD_CIRCUMFLEX = 0xfe52;
+    const DEAD_TILDE = 0xfe53;
+    const DEAD_DIAERESIS = 0xfe57;
+    const DEAD_ABOVE_RING = 0xfe58;
+    const DEAD_CARON = 0xfe5a;
+    const DEAD_CEDILLA = 0xfe5b;
+}
+
+impl KeyCode {
+    /// Returns the character typed by the key, if any.
+    ///
+    /// Latin-1 and Unicode keysyms, legacy keysyms of other scripts and the
+    /// printable keys of the keypad are supported.
+    pub fn to_char(self) -> Option<char> {
+        match self.0 {
+            // Latin-1 keysyms have the value of their code point.
+            0x20..=0x7e | 0xa0..=0xff => char::from_u32(self.0),
+            // Other Unicode characters are offset by `0x01000000`.
+            0x0100_0100..=0x0110_ffff => char::from_u32(self.0 - 0x0100_0000),
+            0xffb0..=0xffb9 => char::from_digit(self.0 - 0xffb0, 10),
+            0xff80 => Some(' '),
+            0xffaa => Some('*'),
+            0xffab => Some('+'),
+            0xffac => Some(','),
+            0xffad => Some('-'),
+            0xffae => Some('.'),
+            0xffaf => Some('/'),
+            0xffbd => Some('='),
+            keysym => LEGACY_KEYSYMS
+                .binary_search_by_key(&keysym, |&(keysym, _)| keysym)
+                .ok()
+                .map(|index| LEGACY_KEYSYMS[index].1),
+        }
+    }
+
+    /// Returns the keysym of the key typing `c`.
+    ///
+    /// This is the inverse of [`KeyCode::to_char`] for characters that are not
+    /// typed with the keypad.
+    pub fn from_char(c: char) -> Self {
+        match c as u32 {
+            code @ (0x20..=0x7e | 0xa0..=0xff) => Self(code),
+            code => Self(code + 0x0100_0000),
+        }
+    }
+
+    /// Returns whether the key is a modifier, such as Shift or Control.
+    pub fn is_modifier(self) -> bool {
+        matches!(self.0, 0xffe1..=0xffee | 0xfe03 | 0xff7e | 0xff7f)
+    }
+
+    /// Returns whether the key is a dead key, which adds an accent to the
+    /// next letter instead of typing a character.
+    pub fn is_dead(self) -> bool {
+        matches!(self.0, 0xfe50..=0xfe8f)
+    }
 }
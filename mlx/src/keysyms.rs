@@ -0,0 +1,768 @@
+//! The characters of the legacy keysyms, which predate the direct mapping of
+//! Unicode code points to keysyms. Generated from X11's `keysymdef.h`.
+
+/// Legacy keysyms and their character, sorted by keysym.
+pub(crate) static LEGACY_KEYSYMS: [(u32, char); 762] = [
+    (0x01a1, '\u{0104}'), // Aogonek
+    (0x01a2, '\u{02d8}'), // breve
+    (0x01a3, '\u{0141}'), // Lstroke
+    (0x01a5, '\u{013d}'), // Lcaron
+    (0x01a6, '\u{015a}'), // Sacute
+    (0x01a9, '\u{0160}'), // Scaron
+    (0x01aa, '\u{015e}'), // Scedilla
+    (0x01ab, '\u{0164}'), // Tcaron
+    (0x01ac, '\u{0179}'), // Zacute
+    (0x01ae, '\u{017d}'), // Zcaron
+    (0x01af, '\u{017b}'), // Zabovedot
+    (0x01b1, '\u{0105}'), // aogonek
+    (0x01b2, '\u{02db}'), // ogonek
+    (0x01b3, '\u{0142}'), // lstroke
+    (0x01b5, '\u{013e}'), // lcaron
+    (0x01b6, '\u{015b}'), // sacute
+    (0x01b7, '\u{02c7}'), // caron
+    (0x01b9, '\u{0161}'), // scaron
+    (0x01ba, '\u{015f}'), // scedilla
+    (0x01bb, '\u{0165}'), // tcaron
+    (0x01bc, '\u{017a}'), // zacute
+    (0x01bd, '\u{02dd}'), // doubleacute
+    (0x01be, '\u{017e}'), // zcaron
+    (0x01bf, '\u{017c}'), // zabovedot
+    (0x01c0, '\u{0154}'), // Racute
+    (0x01c3, '\u{0102}'), // Abreve
+    (0x01c5, '\u{0139}'), // Lacute
+    (0x01c6, '\u{0106}'), // Cacute
+    (0x01c8, '\u{010c}'), // Ccaron
+    (0x01ca, '\u{0118}'), // Eogonek
+    (0x01cc, '\u{011a}'), // Ecaron
+    (0x01cf, '\u{010e}'), // Dcaron
+    (0x01d0, '\u{0110}'), // Dstroke
+    (0x01d1, '\u{0143}'), // Nacute
+    (0x01d2, '\u{0147}'), // Ncaron
+    (0x01d5, '\u{0150}'), // Odoubleacute
+    (0x01d8, '\u{0158}'), // Rcaron
+    (0x01d9, '\u{016e}'), // Uring
+    (0x01db, '\u{0170}'), // Udoubleacute
+    (0x01de, '\u{0162}'), // Tcedilla
+    (0x01e0, '\u{0155}'), // racute
+    (0x01e3, '\u{0103}'), // abreve
+    (0x01e5, '\u{013a}'), // lacute
+    (0x01e6, '\u{0107}'), // cacute
+    (0x01e8, '\u{010d}'), // ccaron
+    (0x01ea, '\u{0119}'), // eogonek
+    (0x01ec, '\u{011b}'), // ecaron
+    (0x01ef, '\u{010f}'), // dcaron
+    (0x01f0, '\u{0111}'), // dstroke
+    (0x01f1, '\u{0144}'), // nacute
+    (0x01f2, '\u{0148}'), // ncaron
+    (0x01f5, '\u{0151}'), // odoubleacute
+    (0x01f8, '\u{0159}'), // rcaron
+    (0x01f9, '\u{016f}'), // uring
+    (0x01fb, '\u{0171}'), // udoubleacute
+    (0x01fe, '\u{0163}'), // tcedilla
+    (0x01ff, '\u{02d9}'), // abovedot
+    (0x02a1, '\u{0126}'), // Hstroke
+    (0x02a6, '\u{0124}'), // Hcircumflex
+    (0x02a9, '\u{0130}'), // Iabovedot
+    (0x02ab, '\u{011e}'), // Gbreve
+    (0x02ac, '\u{0134}'), // Jcircumflex
+    (0x02b1, '\u{0127}'), // hstroke
+    (0x02b6, '\u{0125}'), // hcircumflex
+    (0x02b9, '\u{0131}'), // idotless
+    (0x02bb, '\u{011f}'), // gbreve
+    (0x02bc, '\u{0135}'), // jcircumflex
+    (0x02c5, '\u{010a}'), // Cabovedot
+    (0x02c6, '\u{0108}'), // Ccircumflex
+    (0x02d5, '\u{0120}'), // Gabovedot
+    (0x02d8, '\u{011c}'), // Gcircumflex
+    (0x02dd, '\u{016c}'), // Ubreve
+    (0x02de, '\u{015c}'), // Scircumflex
+    (0x02e5, '\u{010b}'), // cabovedot
+    (0x02e6, '\u{0109}'), // ccircumflex
+    (0x02f5, '\u{0121}'), // gabovedot
+    (0x02f8, '\u{011d}'), // gcircumflex
+    (0x02fd, '\u{016d}'), // ubreve
+    (0x02fe, '\u{015d}'), // scircumflex
+    (0x03a2, '\u{0138}'), // kra
+    (0x03a3, '\u{0156}'), // Rcedilla
+    (0x03a5, '\u{0128}'), // Itilde
+    (0x03a6, '\u{013b}'), // Lcedilla
+    (0x03aa, '\u{0112}'), // Emacron
+    (0x03ab, '\u{0122}'), // Gcedilla
+    (0x03ac, '\u{0166}'), // Tslash
+    (0x03b3, '\u{0157}'), // rcedilla
+    (0x03b5, '\u{0129}'), // itilde
+    (0x03b6, '\u{013c}'), // lcedilla
+    (0x03ba, '\u{0113}'), // emacron
+    (0x03bb, '\u{0123}'), // gcedilla
+    (0x03bc, '\u{0167}'), // tslash
+    (0x03bd, '\u{014a}'), // ENG
+    (0x03bf, '\u{014b}'), // eng
+    (0x03c0, '\u{0100}'), // Amacron
+    (0x03c7, '\u{012e}'), // Iogonek
+    (0x03cc, '\u{0116}'), // Eabovedot
+    (0x03cf, '\u{012a}'), // Imacron
+    (0x03d1, '\u{0145}'), // Ncedilla
+    (0x03d2, '\u{014c}'), // Omacron
+    (0x03d3, '\u{0136}'), // Kcedilla
+    (0x03d9, '\u{0172}'), // Uogonek
+    (0x03dd, '\u{0168}'), // Utilde
+    (0x03de, '\u{016a}'), // Umacron
+    (0x03e0, '\u{0101}'), // amacron
+    (0x03e7, '\u{012f}'), // iogonek
+    (0x03ec, '\u{0117}'), // eabovedot
+    (0x03ef, '\u{012b}'), // imacron
+    (0x03f1, '\u{0146}'), // ncedilla
+    (0x03f2, '\u{014d}'), // omacron
+    (0x03f3, '\u{0137}'), // kcedilla
+    (0x03f9, '\u{0173}'), // uogonek
+    (0x03fd, '\u{0169}'), // utilde
+    (0x03fe, '\u{016b}'), // umacron
+    (0x047e, '\u{203e}'), // overline
+    (0x04a1, '\u{3002}'), // kana_fullstop
+    (0x04a2, '\u{300c}'), // kana_openingbracket
+    (0x04a3, '\u{300d}'), // kana_closingbracket
+    (0x04a4, '\u{3001}'), // kana_comma
+    (0x04a5, '\u{30fb}'), // kana_conjunctive
+    (0x04a6, '\u{30f2}'), // kana_WO
+    (0x04a7, '\u{30a1}'), // kana_a
+    (0x04a8, '\u{30a3}'), // kana_i
+    (0x04a9, '\u{30a5}'), // kana_u
+    (0x04aa, '\u{30a7}'), // kana_e
+    (0x04ab, '\u{30a9}'), // kana_o
+    (0x04ac, '\u{30e3}'), // kana_ya
+    (0x04ad, '\u{30e5}'), // kana_yu
+    (0x04ae, '\u{30e7}'), // kana_yo
+    (0x04af, '\u{30c3}'), // kana_tsu
+    (0x04b0, '\u{30fc}'), // prolongedsound
+    (0x04b1, '\u{30a2}'), // kana_A
+    (0x04b2, '\u{30a4}'), // kana_I
+    (0x04b3, '\u{30a6}'), // kana_U
+    (0x04b4, '\u{30a8}'), // kana_E
+    (0x04b5, '\u{30aa}'), // kana_O
+    (0x04b6, '\u{30ab}'), // kana_KA
+    (0x04b7, '\u{30ad}'), // kana_KI
+    (0x04b8, '\u{30af}'), // kana_KU
+    (0x04b9, '\u{30b1}'), // kana_KE
+    (0x04ba, '\u{30b3}'), // kana_KO
+    (0x04bb, '\u{30b5}'), // kana_SA
+    (0x04bc, '\u{30b7}'), // kana_SHI
+    (0x04bd, '\u{30b9}'), // kana_SU
+    (0x04be, '\u{30bb}'), // kana_SE
+    (0x04bf, '\u{30bd}'), // kana_SO
+    (0x04c0, '\u{30bf}'), // kana_TA
+    (0x04c1, '\u{30c1}'), // kana_CHI
+    (0x04c2, '\u{30c4}'), // kana_TSU
+    (0x04c3, '\u{30c6}'), // kana_TE
+    (0x04c4, '\u{30c8}'), // kana_TO
+    (0x04c5, '\u{30ca}'), // kana_NA
+    (0x04c6, '\u{30cb}'), // kana_NI
+    (0x04c7, '\u{30cc}'), // kana_NU
+    (0x04c8, '\u{30cd}'), // kana_NE
+    (0x04c9, '\u{30ce}'), // kana_NO
+    (0x04ca, '\u{30cf}'), // kana_HA
+    (0x04cb, '\u{30d2}'), // kana_HI
+    (0x04cc, '\u{30d5}'), // kana_FU
+    (0x04cd, '\u{30d8}'), // kana_HE
+    (0x04ce, '\u{30db}'), // kana_HO
+    (0x04cf, '\u{30de}'), // kana_MA
+    (0x04d0, '\u{30df}'), // kana_MI
+    (0x04d1, '\u{30e0}'), // kana_MU
+    (0x04d2, '\u{30e1}'), // kana_ME
+    (0x04d3, '\u{30e2}'), // kana_MO
+    (0x04d4, '\u{30e4}'), // kana_YA
+    (0x04d5, '\u{30e6}'), // kana_YU
+    (0x04d6, '\u{30e8}'), // kana_YO
+    (0x04d7, '\u{30e9}'), // kana_RA
+    (0x04d8, '\u{30ea}'), // kana_RI
+    (0x04d9, '\u{30eb}'), // kana_RU
+    (0x04da, '\u{30ec}'), // kana_RE
+    (0x04db, '\u{30ed}'), // kana_RO
+    (0x04dc, '\u{30ef}'), // kana_WA
+    (0x04dd, '\u{30f3}'), // kana_N
+    (0x04de, '\u{309b}'), // voicedsound
+    (0x04df, '\u{309c}'), // semivoicedsound
+    (0x05ac, '\u{060c}'), // Arabic_comma
+    (0x05bb, '\u{061b}'), // Arabic_semicolon
+    (0x05bf, '\u{061f}'), // Arabic_question_mark
+    (0x05c1, '\u{0621}'), // Arabic_hamza
+    (0x05c2, '\u{0622}'), // Arabic_maddaonalef
+    (0x05c3, '\u{0623}'), // Arabic_hamzaonalef
+    (0x05c4, '\u{0624}'), // Arabic_hamzaonwaw
+    (0x05c5, '\u{0625}'), // Arabic_hamzaunderalef
+    (0x05c6, '\u{0626}'), // Arabic_hamzaonyeh
+    (0x05c7, '\u{0627}'), // Arabic_alef
+    (0x05c8, '\u{0628}'), // Arabic_beh
+    (0x05c9, '\u{0629}'), // Arabic_tehmarbuta
+    (0x05ca, '\u{062a}'), // Arabic_teh
+    (0x05cb, '\u{062b}'), // Arabic_theh
+    (0x05cc, '\u{062c}'), // Arabic_jeem
+    (0x05cd, '\u{062d}'), // Arabic_hah
+    (0x05ce, '\u{062e}'), // Arabic_khah
+    (0x05cf, '\u{062f}'), // Arabic_dal
+    (0x05d0, '\u{0630}'), // Arabic_thal
+    (0x05d1, '\u{0631}'), // Arabic_ra
+    (0x05d2, '\u{0632}'), // Arabic_zain
+    (0x05d3, '\u{0633}'), // Arabic_seen
+    (0x05d4, '\u{0634}'), // Arabic_sheen
+    (0x05d5, '\u{0635}'), // Arabic_sad
+    (0x05d6, '\u{0636}'), // Arabic_dad
+    (0x05d7, '\u{0637}'), // Arabic_tah
+    (0x05d8, '\u{0638}'), // Arabic_zah
+    (0x05d9, '\u{0639}'), // Arabic_ain
+    (0x05da, '\u{063a}'), // Arabic_ghain
+    (0x05e0, '\u{0640}'), // Arabic_tatweel
+    (0x05e1, '\u{0641}'), // Arabic_feh
+    (0x05e2, '\u{0642}'), // Arabic_qaf
+    (0x05e3, '\u{0643}'), // Arabic_kaf
+    (0x05e4, '\u{0644}'), // Arabic_lam
+    (0x05e5, '\u{0645}'), // Arabic_meem
+    (0x05e6, '\u{0646}'), // Arabic_noon
+    (0x05e7, '\u{0647}'), // Arabic_ha
+    (0x05e8, '\u{0648}'), // Arabic_waw
+    (0x05e9, '\u{0649}'), // Arabic_alefmaksura
+    (0x05ea, '\u{064a}'), // Arabic_yeh
+    (0x05eb, '\u{064b}'), // Arabic_fathatan
+    (0x05ec, '\u{064c}'), // Arabic_dammatan
+    (0x05ed, '\u{064d}'), // Arabic_kasratan
+    (0x05ee, '\u{064e}'), // Arabic_fatha
+    (0x05ef, '\u{064f}'), // Arabic_damma
+    (0x05f0, '\u{0650}'), // Arabic_kasra
+    (0x05f1, '\u{0651}'), // Arabic_shadda
+    (0x05f2, '\u{0652}'), // Arabic_sukun
+    (0x06a1, '\u{0452}'), // Serbian_dje
+    (0x06a2, '\u{0453}'), // Macedonia_gje
+    (0x06a3, '\u{0451}'), // Cyrillic_io
+    (0x06a4, '\u{0454}'), // Ukrainian_ie
+    (0x06a5, '\u{0455}'), // Macedonia_dse
+    (0x06a6, '\u{0456}'), // Ukrainian_i
+    (0x06a7, '\u{0457}'), // Ukrainian_yi
+    (0x06a8, '\u{0458}'), // Cyrillic_je
+    (0x06a9, '\u{0459}'), // Cyrillic_lje
+    (0x06aa, '\u{045a}'), // Cyrillic_nje
+    (0x06ab, '\u{045b}'), // Serbian_tshe
+    (0x06ac, '\u{045c}'), // Macedonia_kje
+    (0x06ad, '\u{0491}'), // Ukrainian_ghe_with_upturn
+    (0x06ae, '\u{045e}'), // Byelorussian_shortu
+    (0x06af, '\u{045f}'), // Cyrillic_dzhe
+    (0x06b0, '\u{2116}'), // numerosign
+    (0x06b1, '\u{0402}'), // Serbian_DJE
+    (0x06b2, '\u{0403}'), // Macedonia_GJE
+    (0x06b3, '\u{0401}'), // Cyrillic_IO
+    (0x06b4, '\u{0404}'), // Ukrainian_IE
+    (0x06b5, '\u{0405}'), // Macedonia_DSE
+    (0x06b6, '\u{0406}'), // Ukrainian_I
+    (0x06b7, '\u{0407}'), // Ukrainian_YI
+    (0x06b8, '\u{0408}'), // Cyrillic_JE
+    (0x06b9, '\u{0409}'), // Cyrillic_LJE
+    (0x06ba, '\u{040a}'), // Cyrillic_NJE
+    (0x06bb, '\u{040b}'), // Serbian_TSHE
+    (0x06bc, '\u{040c}'), // Macedonia_KJE
+    (0x06bd, '\u{0490}'), // Ukrainian_GHE_WITH_UPTURN
+    (0x06be, '\u{040e}'), // Byelorussian_SHORTU
+    (0x06bf, '\u{040f}'), // Cyrillic_DZHE
+    (0x06c0, '\u{044e}'), // Cyrillic_yu
+    (0x06c1, '\u{0430}'), // Cyrillic_a
+    (0x06c2, '\u{0431}'), // Cyrillic_be
+    (0x06c3, '\u{0446}'), // Cyrillic_tse
+    (0x06c4, '\u{0434}'), // Cyrillic_de
+    (0x06c5, '\u{0435}'), // Cyrillic_ie
+    (0x06c6, '\u{0444}'), // Cyrillic_ef
+    (0x06c7, '\u{0433}'), // Cyrillic_ghe
+    (0x06c8, '\u{0445}'), // Cyrillic_ha
+    (0x06c9, '\u{0438}'), // Cyrillic_i
+    (0x06ca, '\u{0439}'), // Cyrillic_shorti
+    (0x06cb, '\u{043a}'), // Cyrillic_ka
+    (0x06cc, '\u{043b}'), // Cyrillic_el
+    (0x06cd, '\u{043c}'), // Cyrillic_em
+    (0x06ce, '\u{043d}'), // Cyrillic_en
+    (0x06cf, '\u{043e}'), // Cyrillic_o
+    (0x06d0, '\u{043f}'), // Cyrillic_pe
+    (0x06d1, '\u{044f}'), // Cyrillic_ya
+    (0x06d2, '\u{0440}'), // Cyrillic_er
+    (0x06d3, '\u{0441}'), // Cyrillic_es
+    (0x06d4, '\u{0442}'), // Cyrillic_te
+    (0x06d5, '\u{0443}'), // Cyrillic_u
+    (0x06d6, '\u{0436}'), // Cyrillic_zhe
+    (0x06d7, '\u{0432}'), // Cyrillic_ve
+    (0x06d8, '\u{044c}'), // Cyrillic_softsign
+    (0x06d9, '\u{044b}'), // Cyrillic_yeru
+    (0x06da, '\u{0437}'), // Cyrillic_ze
+    (0x06db, '\u{0448}'), // Cyrillic_sha
+    (0x06dc, '\u{044d}'), // Cyrillic_e
+    (0x06dd, '\u{0449}'), // Cyrillic_shcha
+    (0x06de, '\u{0447}'), // Cyrillic_che
+    (0x06df, '\u{044a}'), // Cyrillic_hardsign
+    (0x06e0, '\u{042e}'), // Cyrillic_YU
+    (0x06e1, '\u{0410}'), // Cyrillic_A
+    (0x06e2, '\u{0411}'), // Cyrillic_BE
+    (0x06e3, '\u{0426}'), // Cyrillic_TSE
+    (0x06e4, '\u{0414}'), // Cyrillic_DE
+    (0x06e5, '\u{0415}'), // Cyrillic_IE
+    (0x06e6, '\u{0424}'), // Cyrillic_EF
+    (0x06e7, '\u{0413}'), // Cyrillic_GHE
+    (0x06e8, '\u{0425}'), // Cyrillic_HA
+    (0x06e9, '\u{0418}'), // Cyrillic_I
+    (0x06ea, '\u{0419}'), // Cyrillic_SHORTI
+    (0x06eb, '\u{041a}'), // Cyrillic_KA
+    (0x06ec, '\u{041b}'), // Cyrillic_EL
+    (0x06ed, '\u{041c}'), // Cyrillic_EM
+    (0x06ee, '\u{041d}'), // Cyrillic_EN
+    (0x06ef, '\u{041e}'), // Cyrillic_O
+    (0x06f0, '\u{041f}'), // Cyrillic_PE
+    (0x06f1, '\u{042f}'), // Cyrillic_YA
+    (0x06f2, '\u{0420}'), // Cyrillic_ER
+    (0x06f3, '\u{0421}'), // Cyrillic_ES
+    (0x06f4, '\u{0422}'), // Cyrillic_TE
+    (0x06f5, '\u{0423}'), // Cyrillic_U
+    (0x06f6, '\u{0416}'), // Cyrillic_ZHE
+    (0x06f7, '\u{0412}'), // Cyrillic_VE
+    (0x06f8, '\u{042c}'), // Cyrillic_SOFTSIGN
+    (0x06f9, '\u{042b}'), // Cyrillic_YERU
+    (0x06fa, '\u{0417}'), // Cyrillic_ZE
+    (0x06fb, '\u{0428}'), // Cyrillic_SHA
+    (0x06fc, '\u{042d}'), // Cyrillic_E
+    (0x06fd, '\u{0429}'), // Cyrillic_SHCHA
+    (0x06fe, '\u{0427}'), // Cyrillic_CHE
+    (0x06ff, '\u{042a}'), // Cyrillic_HARDSIGN
+    (0x07a1, '\u{0386}'), // Greek_ALPHAaccent
+    (0x07a2, '\u{0388}'), // Greek_EPSILONaccent
+    (0x07a3, '\u{0389}'), // Greek_ETAaccent
+    (0x07a4, '\u{038a}'), // Greek_IOTAaccent
+    (0x07a5, '\u{03aa}'), // Greek_IOTAdieresis
+    (0x07a7, '\u{038c}'), // Greek_OMICRONaccent
+    (0x07a8, '\u{038e}'), // Greek_UPSILONaccent
+    (0x07a9, '\u{03ab}'), // Greek_UPSILONdieresis
+    (0x07ab, '\u{038f}'), // Greek_OMEGAaccent
+    (0x07ae, '\u{0385}'), // Greek_accentdieresis
+    (0x07af, '\u{2015}'), // Greek_horizbar
+    (0x07b1, '\u{03ac}'), // Greek_alphaaccent
+    (0x07b2, '\u{03ad}'), // Greek_epsilonaccent
+    (0x07b3, '\u{03ae}'), // Greek_etaaccent
+    (0x07b4, '\u{03af}'), // Greek_iotaaccent
+    (0x07b5, '\u{03ca}'), // Greek_iotadieresis
+    (0x07b6, '\u{0390}'), // Greek_iotaaccentdieresis
+    (0x07b7, '\u{03cc}'), // Greek_omicronaccent
+    (0x07b8, '\u{03cd}'), // Greek_upsilonaccent
+    (0x07b9, '\u{03cb}'), // Greek_upsilondieresis
+    (0x07ba, '\u{03b0}'), // Greek_upsilonaccentdieresis
+    (0x07bb, '\u{03ce}'), // Greek_omegaaccent
+    (0x07c1, '\u{0391}'), // Greek_ALPHA
+    (0x07c2, '\u{0392}'), // Greek_BETA
+    (0x07c3, '\u{0393}'), // Greek_GAMMA
+    (0x07c4, '\u{0394}'), // Greek_DELTA
+    (0x07c5, '\u{0395}'), // Greek_EPSILON
+    (0x07c6, '\u{0396}'), // Greek_ZETA
+    (0x07c7, '\u{0397}'), // Greek_ETA
+    (0x07c8, '\u{0398}'), // Greek_THETA
+    (0x07c9, '\u{0399}'), // Greek_IOTA
+    (0x07ca, '\u{039a}'), // Greek_KAPPA
+    (0x07cb, '\u{039b}'), // Greek_LAMDA
+    (0x07cc, '\u{039c}'), // Greek_MU
+    (0x07cd, '\u{039d}'), // Greek_NU
+    (0x07ce, '\u{039e}'), // Greek_XI
+    (0x07cf, '\u{039f}'), // Greek_OMICRON
+    (0x07d0, '\u{03a0}'), // Greek_PI
+    (0x07d1, '\u{03a1}'), // Greek_RHO
+    (0x07d2, '\u{03a3}'), // Greek_SIGMA
+    (0x07d4, '\u{03a4}'), // Greek_TAU
+    (0x07d5, '\u{03a5}'), // Greek_UPSILON
+    (0x07d6, '\u{03a6}'), // Greek_PHI
+    (0x07d7, '\u{03a7}'), // Greek_CHI
+    (0x07d8, '\u{03a8}'), // Greek_PSI
+    (0x07d9, '\u{03a9}'), // Greek_OMEGA
+    (0x07e1, '\u{03b1}'), // Greek_alpha
+    (0x07e2, '\u{03b2}'), // Greek_beta
+    (0x07e3, '\u{03b3}'), // Greek_gamma
+    (0x07e4, '\u{03b4}'), // Greek_delta
+    (0x07e5, '\u{03b5}'), // Greek_epsilon
+    (0x07e6, '\u{03b6}'), // Greek_zeta
+    (0x07e7, '\u{03b7}'), // Greek_eta
+    (0x07e8, '\u{03b8}'), // Greek_theta
+    (0x07e9, '\u{03b9}'), // Greek_iota
+    (0x07ea, '\u{03ba}'), // Greek_kappa
+    (0x07eb, '\u{03bb}'), // Greek_lamda
+    (0x07ec, '\u{03bc}'), // Greek_mu
+    (0x07ed, '\u{03bd}'), // Greek_nu
+    (0x07ee, '\u{03be}'), // Greek_xi
+    (0x07ef, '\u{03bf}'), // Greek_omicron
+    (0x07f0, '\u{03c0}'), // Greek_pi
+    (0x07f1, '\u{03c1}'), // Greek_rho
+    (0x07f2, '\u{03c3}'), // Greek_sigma
+    (0x07f3, '\u{03c2}'), // Greek_finalsmallsigma
+    (0x07f4, '\u{03c4}'), // Greek_tau
+    (0x07f5, '\u{03c5}'), // Greek_upsilon
+    (0x07f6, '\u{03c6}'), // Greek_phi
+    (0x07f7, '\u{03c7}'), // Greek_chi
+    (0x07f8, '\u{03c8}'), // Greek_psi
+    (0x07f9, '\u{03c9}'), // Greek_omega
+    (0x08a1, '\u{23b7}'), // leftradical
+    (0x08a2, '\u{250c}'), // topleftradical
+    (0x08a3, '\u{2500}'), // horizconnector
+    (0x08a4, '\u{2320}'), // topintegral
+    (0x08a5, '\u{2321}'), // botintegral
+    (0x08a6, '\u{2502}'), // vertconnector
+    (0x08a7, '\u{23a1}'), // topleftsqbracket
+    (0x08a8, '\u{23a3}'), // botleftsqbracket
+    (0x08a9, '\u{23a4}'), // toprightsqbracket
+    (0x08aa, '\u{23a6}'), // botrightsqbracket
+    (0x08ab, '\u{239b}'), // topleftparens
+    (0x08ac, '\u{239d}'), // botleftparens
+    (0x08ad, '\u{239e}'), // toprightparens
+    (0x08ae, '\u{23a0}'), // botrightparens
+    (0x08af, '\u{23a8}'), // leftmiddlecurlybrace
+    (0x08b0, '\u{23ac}'), // rightmiddlecurlybrace
+    (0x08bc, '\u{2264}'), // lessthanequal
+    (0x08bd, '\u{2260}'), // notequal
+    (0x08be, '\u{2265}'), // greaterthanequal
+    (0x08bf, '\u{222b}'), // integral
+    (0x08c0, '\u{2234}'), // therefore
+    (0x08c1, '\u{221d}'), // variation
+    (0x08c2, '\u{221e}'), // infinity
+    (0x08c5, '\u{2207}'), // nabla
+    (0x08c8, '\u{223c}'), // approximate
+    (0x08c9, '\u{2243}'), // similarequal
+    (0x08cd, '\u{21d4}'), // ifonlyif
+    (0x08ce, '\u{21d2}'), // implies
+    (0x08cf, '\u{2261}'), // identical
+    (0x08d6, '\u{221a}'), // radical
+    (0x08da, '\u{2282}'), // includedin
+    (0x08db, '\u{2283}'), // includes
+    (0x08dc, '\u{2229}'), // intersection
+    (0x08dd, '\u{222a}'), // union
+    (0x08de, '\u{2227}'), // logicaland
+    (0x08df, '\u{2228}'), // logicalor
+    (0x08ef, '\u{2202}'), // partialderivative
+    (0x08f6, '\u{0192}'), // function
+    (0x08fb, '\u{2190}'), // leftarrow
+    (0x08fc, '\u{2191}'), // uparrow
+    (0x08fd, '\u{2192}'), // rightarrow
+    (0x08fe, '\u{2193}'), // downarrow
+    (0x09e0, '\u{25c6}'), // soliddiamond
+    (0x09e1, '\u{2592}'), // checkerboard
+    (0x09e2, '\u{2409}'), // ht
+    (0x09e3, '\u{240c}'), // ff
+    (0x09e4, '\u{240d}'), // cr
+    (0x09e5, '\u{240a}'), // lf
+    (0x09e8, '\u{2424}'), // nl
+    (0x09e9, '\u{240b}'), // vt
+    (0x09ea, '\u{2518}'), // lowrightcorner
+    (0x09eb, '\u{2510}'), // uprightcorner
+    (0x09ec, '\u{250c}'), // upleftcorner
+    (0x09ed, '\u{2514}'), // lowleftcorner
+    (0x09ee, '\u{253c}'), // crossinglines
+    (0x09ef, '\u{23ba}'), // horizlinescan1
+    (0x09f0, '\u{23bb}'), // horizlinescan3
+    (0x09f1, '\u{2500}'), // horizlinescan5
+    (0x09f2, '\u{23bc}'), // horizlinescan7
+    (0x09f3, '\u{23bd}'), // horizlinescan9
+    (0x09f4, '\u{251c}'), // leftt
+    (0x09f5, '\u{2524}'), // rightt
+    (0x09f6, '\u{2534}'), // bott
+    (0x09f7, '\u{252c}'), // topt
+    (0x09f8, '\u{2502}'), // vertbar
+    (0x0aa1, '\u{2003}'), // emspace
+    (0x0aa2, '\u{2002}'), // enspace
+    (0x0aa3, '\u{2004}'), // em3space
+    (0x0aa4, '\u{2005}'), // em4space
+    (0x0aa5, '\u{2007}'), // digitspace
+    (0x0aa6, '\u{2008}'), // punctspace
+    (0x0aa7, '\u{2009}'), // thinspace
+    (0x0aa8, '\u{200a}'), // hairspace
+    (0x0aa9, '\u{2014}'), // emdash
+    (0x0aaa, '\u{2013}'), // endash
+    (0x0aac, '\u{2423}'), // signifblank
+    (0x0aae, '\u{2026}'), // ellipsis
+    (0x0aaf, '\u{2025}'), // doubbaselinedot
+    (0x0ab0, '\u{2153}'), // onethird
+    (0x0ab1, '\u{2154}'), // twothirds
+    (0x0ab2, '\u{2155}'), // onefifth
+    (0x0ab3, '\u{2156}'), // twofifths
+    (0x0ab4, '\u{2157}'), // threefifths
+    (0x0ab5, '\u{2158}'), // fourfifths
+    (0x0ab6, '\u{2159}'), // onesixth
+    (0x0ab7, '\u{215a}'), // fivesixths
+    (0x0ab8, '\u{2105}'), // careof
+    (0x0abb, '\u{2012}'), // figdash
+    (0x0abc, '\u{2329}'), // leftanglebracket
+    (0x0abd, '\u{002e}'), // decimalpoint
+    (0x0abe, '\u{232a}'), // rightanglebracket
+    (0x0ac3, '\u{215b}'), // oneeighth
+    (0x0ac4, '\u{215c}'), // threeeighths
+    (0x0ac5, '\u{215d}'), // fiveeighths
+    (0x0ac6, '\u{215e}'), // seveneighths
+    (0x0ac9, '\u{2122}'), // trademark
+    (0x0aca, '\u{2613}'), // signaturemark
+    (0x0acc, '\u{25c1}'), // leftopentriangle
+    (0x0acd, '\u{25b7}'), // rightopentriangle
+    (0x0ace, '\u{25cb}'), // emopencircle
+    (0x0acf, '\u{25af}'), // emopenrectangle
+    (0x0ad0, '\u{2018}'), // leftsinglequotemark
+    (0x0ad1, '\u{2019}'), // rightsinglequotemark
+    (0x0ad2, '\u{201c}'), // leftdoublequotemark
+    (0x0ad3, '\u{201d}'), // rightdoublequotemark
+    (0x0ad4, '\u{211e}'), // prescription
+    (0x0ad5, '\u{2030}'), // permille
+    (0x0ad6, '\u{2032}'), // minutes
+    (0x0ad7, '\u{2033}'), // seconds
+    (0x0ad9, '\u{271d}'), // latincross
+    (0x0adb, '\u{25ac}'), // filledrectbullet
+    (0x0adc, '\u{25c0}'), // filledlefttribullet
+    (0x0add, '\u{25b6}'), // filledrighttribullet
+    (0x0ade, '\u{25cf}'), // emfilledcircle
+    (0x0adf, '\u{25ae}'), // emfilledrect
+    (0x0ae0, '\u{25e6}'), // enopencircbullet
+    (0x0ae1, '\u{25ab}'), // enopensquarebullet
+    (0x0ae2, '\u{25ad}'), // openrectbullet
+    (0x0ae3, '\u{25b3}'), // opentribulletup
+    (0x0ae4, '\u{25bd}'), // opentribulletdown
+    (0x0ae5, '\u{2606}'), // openstar
+    (0x0ae6, '\u{2022}'), // enfilledcircbullet
+    (0x0ae7, '\u{25aa}'), // enfilledsqbullet
+    (0x0ae8, '\u{25b2}'), // filledtribulletup
+    (0x0ae9, '\u{25bc}'), // filledtribulletdown
+    (0x0aea, '\u{261c}'), // leftpointer
+    (0x0aeb, '\u{261e}'), // rightpointer
+    (0x0aec, '\u{2663}'), // club
+    (0x0aed, '\u{2666}'), // diamond
+    (0x0aee, '\u{2665}'), // heart
+    (0x0af0, '\u{2720}'), // maltesecross
+    (0x0af1, '\u{2020}'), // dagger
+    (0x0af2, '\u{2021}'), // doubledagger
+    (0x0af3, '\u{2713}'), // checkmark
+    (0x0af4, '\u{2717}'), // ballotcross
+    (0x0af5, '\u{266f}'), // musicalsharp
+    (0x0af6, '\u{266d}'), // musicalflat
+    (0x0af7, '\u{2642}'), // malesymbol
+    (0x0af8, '\u{2640}'), // femalesymbol
+    (0x0af9, '\u{260e}'), // telephone
+    (0x0afa, '\u{2315}'), // telephonerecorder
+    (0x0afb, '\u{2117}'), // phonographcopyright
+    (0x0afc, '\u{2038}'), // caret
+    (0x0afd, '\u{201a}'), // singlelowquotemark
+    (0x0afe, '\u{201e}'), // doublelowquotemark
+    (0x0ba3, '\u{003c}'), // leftcaret
+    (0x0ba6, '\u{003e}'), // rightcaret
+    (0x0ba8, '\u{2228}'), // downcaret
+    (0x0ba9, '\u{2227}'), // upcaret
+    (0x0bc0, '\u{00af}'), // overbar
+    (0x0bc2, '\u{22a4}'), // downtack
+    (0x0bc3, '\u{2229}'), // upshoe
+    (0x0bc4, '\u{230a}'), // downstile
+    (0x0bc6, '\u{005f}'), // underbar
+    (0x0bca, '\u{2218}'), // jot
+    (0x0bcc, '\u{2395}'), // quad
+    (0x0bce, '\u{22a5}'), // uptack
+    (0x0bcf, '\u{25cb}'), // circle
+    (0x0bd3, '\u{2308}'), // upstile
+    (0x0bd6, '\u{222a}'), // downshoe
+    (0x0bd8, '\u{2283}'), // rightshoe
+    (0x0bda, '\u{2282}'), // leftshoe
+    (0x0bdc, '\u{22a3}'), // lefttack
+    (0x0bfc, '\u{22a2}'), // righttack
+    (0x0cdf, '\u{2017}'), // hebrew_doublelowline
+    (0x0ce0, '\u{05d0}'), // hebrew_aleph
+    (0x0ce1, '\u{05d1}'), // hebrew_bet
+    (0x0ce2, '\u{05d2}'), // hebrew_gimel
+    (0x0ce3, '\u{05d3}'), // hebrew_dalet
+    (0x0ce4, '\u{05d4}'), // hebrew_he
+    (0x0ce5, '\u{05d5}'), // hebrew_waw
+    (0x0ce6, '\u{05d6}'), // hebrew_zain
+    (0x0ce7, '\u{05d7}'), // hebrew_chet
+    (0x0ce8, '\u{05d8}'), // hebrew_tet
+    (0x0ce9, '\u{05d9}'), // hebrew_yod
+    (0x0cea, '\u{05da}'), // hebrew_finalkaph
+    (0x0ceb, '\u{05db}'), // hebrew_kaph
+    (0x0cec, '\u{05dc}'), // hebrew_lamed
+    (0x0ced, '\u{05dd}'), // hebrew_finalmem
+    (0x0cee, '\u{05de}'), // hebrew_mem
+    (0x0cef, '\u{05df}'), // hebrew_finalnun
+    (0x0cf0, '\u{05e0}'), // hebrew_nun
+    (0x0cf1, '\u{05e1}'), // hebrew_samech
+    (0x0cf2, '\u{05e2}'), // hebrew_ayin
+    (0x0cf3, '\u{05e3}'), // hebrew_finalpe
+    (0x0cf4, '\u{05e4}'), // hebrew_pe
+    (0x0cf5, '\u{05e5}'), // hebrew_finalzade
+    (0x0cf6, '\u{05e6}'), // hebrew_zade
+    (0x0cf7, '\u{05e7}'), // hebrew_qoph
+    (0x0cf8, '\u{05e8}'), // hebrew_resh
+    (0x0cf9, '\u{05e9}'), // hebrew_shin
+    (0x0cfa, '\u{05ea}'), // hebrew_taw
+    (0x0da1, '\u{0e01}'), // Thai_kokai
+    (0x0da2, '\u{0e02}'), // Thai_khokhai
+    (0x0da3, '\u{0e03}'), // Thai_khokhuat
+    (0x0da4, '\u{0e04}'), // Thai_khokhwai
+    (0x0da5, '\u{0e05}'), // Thai_khokhon
+    (0x0da6, '\u{0e06}'), // Thai_khorakhang
+    (0x0da7, '\u{0e07}'), // Thai_ngongu
+    (0x0da8, '\u{0e08}'), // Thai_chochan
+    (0x0da9, '\u{0e09}'), // Thai_choching
+    (0x0daa, '\u{0e0a}'), // Thai_chochang
+    (0x0dab, '\u{0e0b}'), // Thai_soso
+    (0x0dac, '\u{0e0c}'), // Thai_chochoe
+    (0x0dad, '\u{0e0d}'), // Thai_yoying
+    (0x0dae, '\u{0e0e}'), // Thai_dochada
+    (0x0daf, '\u{0e0f}'), // Thai_topatak
+    (0x0db0, '\u{0e10}'), // Thai_thothan
+    (0x0db1, '\u{0e11}'), // Thai_thonangmontho
+    (0x0db2, '\u{0e12}'), // Thai_thophuthao
+    (0x0db3, '\u{0e13}'), // Thai_nonen
+    (0x0db4, '\u{0e14}'), // Thai_dodek
+    (0x0db5, '\u{0e15}'), // Thai_totao
+    (0x0db6, '\u{0e16}'), // Thai_thothung
+    (0x0db7, '\u{0e17}'), // Thai_thothahan
+    (0x0db8, '\u{0e18}'), // Thai_thothong
+    (0x0db9, '\u{0e19}'), // Thai_nonu
+    (0x0dba, '\u{0e1a}'), // Thai_bobaimai
+    (0x0dbb, '\u{0e1b}'), // Thai_popla
+    (0x0dbc, '\u{0e1c}'), // Thai_phophung
+    (0x0dbd, '\u{0e1d}'), // Thai_fofa
+    (0x0dbe, '\u{0e1e}'), // Thai_phophan
+    (0x0dbf, '\u{0e1f}'), // Thai_fofan
+    (0x0dc0, '\u{0e20}'), // Thai_phosamphao
+    (0x0dc1, '\u{0e21}'), // Thai_moma
+    (0x0dc2, '\u{0e22}'), // Thai_yoyak
+    (0x0dc3, '\u{0e23}'), // Thai_rorua
+    (0x0dc4, '\u{0e24}'), // Thai_ru
+    (0x0dc5, '\u{0e25}'), // Thai_loling
+    (0x0dc6, '\u{0e26}'), // Thai_lu
+    (0x0dc7, '\u{0e27}'), // Thai_wowaen
+    (0x0dc8, '\u{0e28}'), // Thai_sosala
+    (0x0dc9, '\u{0e29}'), // Thai_sorusi
+    (0x0dca, '\u{0e2a}'), // Thai_sosua
+    (0x0dcb, '\u{0e2b}'), // Thai_hohip
+    (0x0dcc, '\u{0e2c}'), // Thai_lochula
+    (0x0dcd, '\u{0e2d}'), // Thai_oang
+    (0x0dce, '\u{0e2e}'), // Thai_honokhuk
+    (0x0dcf, '\u{0e2f}'), // Thai_paiyannoi
+    (0x0dd0, '\u{0e30}'), // Thai_saraa
+    (0x0dd1, '\u{0e31}'), // Thai_maihanakat
+    (0x0dd2, '\u{0e32}'), // Thai_saraaa
+    (0x0dd3, '\u{0e33}'), // Thai_saraam
+    (0x0dd4, '\u{0e34}'), // Thai_sarai
+    (0x0dd5, '\u{0e35}'), // Thai_saraii
+    (0x0dd6, '\u{0e36}'), // Thai_saraue
+    (0x0dd7, '\u{0e37}'), // Thai_sarauee
+    (0x0dd8, '\u{0e38}'), // Thai_sarau
+    (0x0dd9, '\u{0e39}'), // Thai_sarauu
+    (0x0dda, '\u{0e3a}'), // Thai_phinthu
+    (0x0ddf, '\u{0e3f}'), // Thai_baht
+    (0x0de0, '\u{0e40}'), // Thai_sarae
+    (0x0de1, '\u{0e41}'), // Thai_saraae
+    (0x0de2, '\u{0e42}'), // Thai_sarao
+    (0x0de3, '\u{0e43}'), // Thai_saraaimaimuan
+    (0x0de4, '\u{0e44}'), // Thai_saraaimaimalai
+    (0x0de5, '\u{0e45}'), // Thai_lakkhangyao
+    (0x0de6, '\u{0e46}'), // Thai_maiyamok
+    (0x0de7, '\u{0e47}'), // Thai_maitaikhu
+    (0x0de8, '\u{0e48}'), // Thai_maiek
+    (0x0de9, '\u{0e49}'), // Thai_maitho
+    (0x0dea, '\u{0e4a}'), // Thai_maitri
+    (0x0deb, '\u{0e4b}'), // Thai_maichattawa
+    (0x0dec, '\u{0e4c}'), // Thai_thanthakhat
+    (0x0ded, '\u{0e4d}'), // Thai_nikhahit
+    (0x0df0, '\u{0e50}'), // Thai_leksun
+    (0x0df1, '\u{0e51}'), // Thai_leknung
+    (0x0df2, '\u{0e52}'), // Thai_leksong
+    (0x0df3, '\u{0e53}'), // Thai_leksam
+    (0x0df4, '\u{0e54}'), // Thai_leksi
+    (0x0df5, '\u{0e55}'), // Thai_lekha
+    (0x0df6, '\u{0e56}'), // Thai_lekhok
+    (0x0df7, '\u{0e57}'), // Thai_lekchet
+    (0x0df8, '\u{0e58}'), // Thai_lekpaet
+    (0x0df9, '\u{0e59}'), // Thai_lekkao
+    (0x0ea1, '\u{3131}'), // Hangul_Kiyeog
+    (0x0ea2, '\u{3132}'), // Hangul_SsangKiyeog
+    (0x0ea3, '\u{3133}'), // Hangul_KiyeogSios
+    (0x0ea4, '\u{3134}'), // Hangul_Nieun
+    (0x0ea5, '\u{3135}'), // Hangul_NieunJieuj
+    (0x0ea6, '\u{3136}'), // Hangul_NieunHieuh
+    (0x0ea7, '\u{3137}'), // Hangul_Dikeud
+    (0x0ea8, '\u{3138}'), // Hangul_SsangDikeud
+    (0x0ea9, '\u{3139}'), // Hangul_Rieul
+    (0x0eaa, '\u{313a}'), // Hangul_RieulKiyeog
+    (0x0eab, '\u{313b}'), // Hangul_RieulMieum
+    (0x0eac, '\u{313c}'), // Hangul_RieulPieub
+    (0x0ead, '\u{313d}'), // Hangul_RieulSios
+    (0x0eae, '\u{313e}'), // Hangul_RieulTieut
+    (0x0eaf, '\u{313f}'), // Hangul_RieulPhieuf
+    (0x0eb0, '\u{3140}'), // Hangul_RieulHieuh
+    (0x0eb1, '\u{3141}'), // Hangul_Mieum
+    (0x0eb2, '\u{3142}'), // Hangul_Pieub
+    (0x0eb3, '\u{3143}'), // Hangul_SsangPieub
+    (0x0eb4, '\u{3144}'), // Hangul_PieubSios
+    (0x0eb5, '\u{3145}'), // Hangul_Sios
+    (0x0eb6, '\u{3146}'), // Hangul_SsangSios
+    (0x0eb7, '\u{3147}'), // Hangul_Ieung
+    (0x0eb8, '\u{3148}'), // Hangul_Jieuj
+    (0x0eb9, '\u{3149}'), // Hangul_SsangJieuj
+    (0x0eba, '\u{314a}'), // Hangul_Cieuc
+    (0x0ebb, '\u{314b}'), // Hangul_Khieuq
+    (0x0ebc, '\u{314c}'), // Hangul_Tieut
+    (0x0ebd, '\u{314d}'), // Hangul_Phieuf
+    (0x0ebe, '\u{314e}'), // Hangul_Hieuh
+    (0x0ebf, '\u{314f}'), // Hangul_A
+    (0x0ec0, '\u{3150}'), // Hangul_AE
+    (0x0ec1, '\u{3151}'), // Hangul_YA
+    (0x0ec2, '\u{3152}'), // Hangul_YAE
+    (0x0ec3, '\u{3153}'), // Hangul_EO
+    (0x0ec4, '\u{3154}'), // Hangul_E
+    (0x0ec5, '\u{3155}'), // Hangul_YEO
+    (0x0ec6, '\u{3156}'), // Hangul_YE
+    (0x0ec7, '\u{3157}'), // Hangul_O
+    (0x0ec8, '\u{3158}'), // Hangul_WA
+    (0x0ec9, '\u{3159}'), // Hangul_WAE
+    (0x0eca, '\u{315a}'), // Hangul_OE
+    (0x0ecb, '\u{315b}'), // Hangul_YO
+    (0x0ecc, '\u{315c}'), // Hangul_U
+    (0x0ecd, '\u{315d}'), // Hangul_WEO
+    (0x0ece, '\u{315e}'), // Hangul_WE
+    (0x0ecf, '\u{315f}'), // Hangul_WI
+    (0x0ed0, '\u{3160}'), // Hangul_YU
+    (0x0ed1, '\u{3161}'), // Hangul_EU
+    (0x0ed2, '\u{3162}'), // Hangul_YI
+    (0x0ed3, '\u{3163}'), // Hangul_I
+    (0x0ed4, '\u{11a8}'), // Hangul_J_Kiyeog
+    (0x0ed5, '\u{11a9}'), // Hangul_J_SsangKiyeog
+    (0x0ed6, '\u{11aa}'), // Hangul_J_KiyeogSios
+    (0x0ed7, '\u{11ab}'), // Hangul_J_Nieun
+    (0x0ed8, '\u{11ac}'), // Hangul_J_NieunJieuj
+    (0x0ed9, '\u{11ad}'), // Hangul_J_NieunHieuh
+    (0x0eda, '\u{11ae}'), // Hangul_J_Dikeud
+    (0x0edb, '\u{11af}'), // Hangul_J_Rieul
+    (0x0edc, '\u{11b0}'), // Hangul_J_RieulKiyeog
+    (0x0edd, '\u{11b1}'), // Hangul_J_RieulMieum
+    (0x0ede, '\u{11b2}'), // Hangul_J_RieulPieub
+    (0x0edf, '\u{11b3}'), // Hangul_J_RieulSios
+    (0x0ee0, '\u{11b4}'), // Hangul_J_RieulTieut
+    (0x0ee1, '\u{11b5}'), // Hangul_J_RieulPhieuf
+    (0x0ee2, '\u{11b6}'), // Hangul_J_RieulHieuh
+    (0x0ee3, '\u{11b7}'), // Hangul_J_Mieum
+    (0x0ee4, '\u{11b8}'), // Hangul_J_Pieub
+    (0x0ee5, '\u{11b9}'), // Hangul_J_PieubSios
+    (0x0ee6, '\u{11ba}'), // Hangul_J_Sios
+    (0x0ee7, '\u{11bb}'), // Hangul_J_SsangSios
+    (0x0ee8, '\u{11bc}'), // Hangul_J_Ieung
+    (0x0ee9, '\u{11bd}'), // Hangul_J_Jieuj
+    (0x0eea, '\u{11be}'), // Hangul_J_Cieuc
+    (0x0eeb, '\u{11bf}'), // Hangul_J_Khieuq
+    (0x0eec, '\u{11c0}'), // Hangul_J_Tieut
+    (0x0eed, '\u{11c1}'), // Hangul_J_Phieuf
+    (0x0eee, '\u{11c2}'), // Hangul_J_Hieuh
+    (0x0eef, '\u{316d}'), // Hangul_RieulYeorinHieuh
+    (0x0ef0, '\u{3171}'), // Hangul_SunkyeongeumMieum
+    (0x0ef1, '\u{3178}'), // Hangul_SunkyeongeumPieub
+    (0x0ef2, '\u{317f}'), // Hangul_PanSios
+    (0x0ef3, '\u{3181}'), // Hangul_KkogjiDalrinIeung
+    (0x0ef4, '\u{3184}'), // Hangul_SunkyeongeumPhieuf
+    (0x0ef5, '\u{3186}'), // Hangul_YeorinHieuh
+    (0x0ef6, '\u{318d}'), // Hangul_AraeA
+    (0x0ef7, '\u{318e}'), // Hangul_AraeAE
+    (0x0ef8, '\u{11eb}'), // Hangul_J_PanSios
+    (0x0ef9, '\u{11f0}'), // Hangul_J_KkogjiDalrinIeung
+    (0x0efa, '\u{11f9}'), // Hangul_J_YeorinHieuh
+    (0x0eff, '\u{20a9}'), // Korean_Won
+    (0x13bc, '\u{0152}'), // OE
+    (0x13bd, '\u{0153}'), // oe
+    (0x13be, '\u{0178}'), // Ydiaeresis
+    (0x20ac, '\u{20ac}'), // EuroSign
+];
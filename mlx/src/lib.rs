@@ -1,5 +1,7 @@
 pub mod raw;
 
+mod x11;
+
 mod dyn_box;
 
 mod window;
@@ -11,8 +13,19 @@ pub use self::mlx::*;
 mod image;
 pub use self::image::*;
 
+mod canvas;
+pub use self::canvas::*;
+
+mod color;
+pub use self::color::*;
+
 mod hook;
 pub use self::hook::*;
 
+mod event;
+pub use self::event::*;
+
 mod keycode;
 pub use self::keycode::*;
+
+mod keysyms;
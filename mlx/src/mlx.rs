@@ -1,7 +1,7 @@
 use std::ffi::{c_void, CStr};
 use std::os::raw::c_int;
 
-use crate::{Image, ImageError, Window, WindowError};
+use crate::{Color, Image, ImageError, Window, WindowError};
 
 /// The that is returned whem [`Mlx::init`] fails.
 #[derive(Debug, Clone, Copy)]
@@ -14,10 +14,6 @@ pub struct Mlx {
 }
 
 impl Mlx {
-    pub(crate) fn wrap_ref(handle: &crate::raw::Mlx) -> &Self {
-        unsafe { std::mem::transmute(handle) }
-    }
-
     /// Returns the raw handle protected by this instance.
     ///
     /// ## Safety
@@ -43,79 +39,104 @@ impl Mlx {
 
     /// Creates a new [`Window`] instance.
     ///
-    /// ## Safety
-    ///
-    /// The produced [`Window`] must be dropped *after* this [`Mlx`] instance.
+    /// The window borrows this instance, so that it is destroyed first.
     #[inline]
-    pub unsafe fn create_window(
+    pub fn create_window(
         &self,
         width: u32,
         height: u32,
         name: &CStr,
-    ) -> Result<Window, WindowError> {
-        Window::create(self.handle, width, height, name)
+    ) -> Result<Window<'_>, WindowError> {
+        Window::create(self, width, height, name)
     }
 
     /// Creates a new empty [`Image`].
-    ///
-    /// ## Safety
-    ///
-    /// The produced [`Image`] must be dropped *after* this [`Mlx`] instance.
     #[inline]
-    pub unsafe fn create_image(&self, width: u32, height: u32) -> Result<Image, ImageError> {
-        Image::create(self.handle, width, height)
+    pub fn create_image(&self, width: u32, height: u32) -> Result<Image<'_>, ImageError> {
+        Image::create(self, width, height)
     }
 
     /// Creates a new image from the content of an XPM-encoded file.
     ///
-    /// ## Safety
-    ///
-    /// The produced [`Image`] must be dropped *after* this [`Mlx`] instance.
+    /// `xpm_data` contains the strings of the XPM array: the values line, the
+    /// colors, then the pixels, as they appear between quotes in the file.
     #[inline]
-    pub unsafe fn create_image_from_xpm(&self, xpm_data: &CStr) -> Result<Image, ImageError> {
-        Image::create_from_xpm(self.handle, xpm_data)
+    pub fn create_image_from_xpm(&self, xpm_data: &[&CStr]) -> Result<Image<'_>, ImageError> {
+        Image::create_from_xpm(self, xpm_data)
     }
 
     /// Creates a new image from an XPM-encoded file.
-    ///
-    /// ## Safety
-    ///
-    /// The produced [`Image`] must be dropped *after* this [`Mlx`] instance.
     #[inline]
-    pub unsafe fn create_image_from_xpm_file(&self, file_path: &CStr) -> Result<Image, ImageError> {
-        Image::create_from_xpm_file(self.handle, file_path)
+    pub fn create_image_from_xpm_file(&self, file_path: &CStr) -> Result<Image<'_>, ImageError> {
+        Image::create_from_xpm_file(self, file_path)
     }
 
-    /// Loops indefinitely until [`Mlx::stop_loop`] is called.
-    pub fn start_loop<'a, F>(&self, mut f: F)
+    /// Loops indefinitely until [`Mlx::stop_loop`] is called, calling `f`
+    /// whenever no event is pending.
+    pub fn start_loop<F>(&self, mut f: F)
     where
-        F: FnMut() + 'a,
+        F: FnMut(),
     {
         unsafe extern "C" fn callback<F: FnMut()>(userdata: *mut c_void) -> c_int {
             (&mut *(userdata as *mut F))();
             0
         }
 
+        // Safety:
+        //  `f` outlives the loop, and is unhooked before this function
+        //  returns, so MiniLibX never calls it once it is dropped.
         unsafe {
             crate::raw::mlx_loop_hook(
                 self.as_raw(),
-                callback::<F>,
+                Some(callback::<F>),
                 &mut f as *mut F as *mut c_void,
-            )
-        };
-
-        unsafe { crate::raw::mlx_loop(self.as_raw()) };
+            );
+            crate::raw::mlx_loop(self.as_raw());
+            crate::raw::mlx_loop_hook(self.as_raw(), None, std::ptr::null_mut());
+        }
     }
 
     /// Stops the currently running loop.
     #[inline]
     pub fn stop_loop(&self) {
+        // Safety:
+        //  The handle is valid for as long as `self` is.
         unsafe { crate::raw::mlx_loop_end(self.as_raw()) };
     }
 
+    /// Returns the size of the screen.
+    pub fn screen_size(&self) -> (u32, u32) {
+        let mut width = 0;
+        let mut height = 0;
+        // Safety:
+        //  The handle is valid for as long as `self` is.
+        unsafe { crate::raw::mlx_get_screen_size(self.as_raw(), &mut width, &mut height) };
+        (width as u32, height as u32)
+    }
+
+    /// Returns the value of `color` as stored in the images of the screen,
+    /// which depends on its depth.
+    pub fn color_value(&self, color: Color) -> u32 {
+        let rgb = color.to_pixel() & 0x00ff_ffff;
+        // Safety:
+        //  The handle is valid for as long as `self` is.
+        unsafe { crate::raw::mlx_get_color_value(self.as_raw(), rgb as c_int) as u32 }
+    }
+
+    /// Sends the pending requests to the X server and waits for them to be
+    /// processed.
+    #[inline]
+    pub fn sync(&self) {
+        // Safety:
+        //  The handle is valid for as long as `self` is.
+        unsafe { crate::raw::mlx_do_sync(self.as_raw()) };
+    }
+
     /// Sets whether key presses should be repeated.
     #[inline]
     pub fn set_autorepeat(&self, yes: bool) {
+        // Safety:
+        //  The handle is valid for as long as `self` is.
         if yes {
             unsafe { crate::raw::mlx_do_key_autorepeaton(self.as_raw()) };
         } else {
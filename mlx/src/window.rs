@@ -1,28 +1,69 @@
 use std::ffi::{c_void, CStr};
-use std::os::raw::c_int;
+use std::os::raw::{c_int, c_ulong};
 
 use crate::dyn_box::DynBox;
-use crate::{Hook, Image, Mlx};
+use crate::x11::{self, WinList, XVar};
+use crate::{Color, Hook, Image, Mlx};
 
 /// An error that might occur whilst creating a window.
 #[derive(Debug, Clone, Copy)]
 pub struct WindowError;
 
 /// An open window.
-pub struct Window {
-    mlx: crate::raw::Mlx,
+///
+/// A window borrows the [`Mlx`] instance it was created with, which ensures
+/// that it is destroyed first.
+pub struct Window<'mlx> {
+    mlx: &'mlx Mlx,
     handle: crate::raw::Window,
 }
 
-impl Window {
-    pub(crate) unsafe fn create(
-        mlx: crate::raw::Mlx,
+/// Keeps a function hooked to an event of a [`Window`].
+///
+/// The function is unhooked when the guard is dropped. As the guard borrows
+/// the window, this always happens before the window is destroyed.
+///
+/// Only one function can be hooked to a given event: dropping the guard of a
+/// function that was replaced unhooks the new one.
+///
+/// If the guard is forgotten, the function is never freed, and stays hooked
+/// until the window is destroyed.
+#[must_use = "the function is unhooked when the guard is dropped"]
+pub struct HookGuard<'a> {
+    window: crate::raw::Window,
+    x_event: c_int,
+    _function: DynBox<'static>,
+    _window: std::marker::PhantomData<&'a ()>,
+}
+
+impl Drop for HookGuard<'_> {
+    fn drop(&mut self) {
+        // Safety:
+        //  The window is still alive, as it is borrowed by the guard.
+        //  MiniLibX skips events whose function is `NULL`.
+        unsafe {
+            crate::raw::mlx_hook(self.window, self.x_event, 0, 0, std::ptr::null_mut());
+        }
+    }
+}
+
+impl<'mlx> Window<'mlx> {
+    pub(crate) fn create(
+        mlx: &'mlx Mlx,
         width: u32,
         height: u32,
         title: &CStr,
     ) -> Result<Self, WindowError> {
-        let handle =
-            crate::raw::mlx_new_window(mlx, width as c_int, height as c_int, title.as_ptr());
+        // Safety:
+        //  The `Mlx` handle is valid for as long as `mlx` is borrowed.
+        let handle = unsafe {
+            crate::raw::mlx_new_window(
+                mlx.as_raw(),
+                width as c_int,
+                height as c_int,
+                title.as_ptr(),
+            )
+        };
 
         if handle.is_null() {
             Err(WindowError)
@@ -43,19 +84,157 @@ impl Window {
 
     /// Returns a reference to the inner `Mlx` instance.
     #[inline]
-    pub fn mlx(&self) -> &Mlx {
-        Mlx::wrap_ref(&self.mlx)
+    pub fn mlx(&self) -> &'mlx Mlx {
+        self.mlx
+    }
+
+    /// Returns the X11 display and window of this window.
+    ///
+    /// ## Safety
+    ///
+    /// The display must not be closed.
+    unsafe fn x11(&self) -> (*mut c_void, c_ulong) {
+        // Safety:
+        //  The handles point to the structures mirrored by `XVar` and
+        //  `WinList`, which live as long as `self`.
+        let mlx = &*(self.mlx.as_raw() as *const XVar);
+        let window = &*(self.handle as *const WinList);
+        (mlx.display, window.window)
+    }
+
+    /// Returns the current size of the window.
+    ///
+    /// MiniLibX does not provide this information, so the window is asked to
+    /// the X server directly.
+    pub fn size(&self) -> (u32, u32) {
+        let (mut root, mut x, mut y, mut border, mut depth) = (0, 0, 0, 0, 0);
+        let (mut width, mut height) = (0, 0);
+
+        // Safety:
+        //  The display and the window are valid for as long as `self` is, and
+        //  every pointer given to `XGetGeometry` is a local variable.
+        unsafe {
+            let (display, window) = self.x11();
+            x11::XGetGeometry(
+                display,
+                window,
+                &mut root,
+                &mut x,
+                &mut y,
+                &mut width,
+                &mut height,
+                &mut border,
+                &mut depth,
+            );
+        }
+
+        (width, height)
+    }
+
+    /// Moves the window so that its top-left corner is at (`x`, `y`) on the
+    /// screen.
+    ///
+    /// MiniLibX does not provide this feature, so the window is moved by the
+    /// X server directly. The window manager may ignore the request.
+    pub fn set_position(&self, x: i32, y: i32) {
+        // Safety:
+        //  The display and the window are valid for as long as `self` is.
+        unsafe {
+            let (display, window) = self.x11();
+            x11::XMoveWindow(display, window, x, y);
+        }
+    }
+
+    /// Draws a single pixel on the window.
+    ///
+    /// This is much slower than drawing on an [`Image`] and putting it on the
+    /// window with [`Window::put_image`].
+    #[inline]
+    pub fn pixel_put(&self, x: i32, y: i32, color: Color) {
+        let rgb = color.to_pixel() & 0x00ff_ffff;
+        // Safety:
+        //  Both handles are valid for as long as `self` is.
+        unsafe { crate::raw::mlx_pixel_put(self.mlx.as_raw(), self.as_raw(), x, y, rgb as c_int) };
+    }
+
+    /// Draws `text` on the window with the current font, (`x`, `y`) being the
+    /// left end of its baseline.
+    #[inline]
+    pub fn string_put(&self, x: i32, y: i32, color: Color, text: &CStr) {
+        let rgb = color.to_pixel() & 0x00ff_ffff;
+        // Safety:
+        //  Both handles are valid for as long as `self` is, and `text` is a
+        //  nul-terminated string that MiniLibX only reads.
+        unsafe {
+            crate::raw::mlx_string_put(
+                self.mlx.as_raw(),
+                self.as_raw(),
+                x,
+                y,
+                rgb as c_int,
+                text.as_ptr(),
+            )
+        };
+    }
+
+    /// Sets the X11 font used by [`Window::string_put`], such as
+    /// `c"-misc-fixed-medium-r-normal--20-*"`.
+    #[inline]
+    pub fn set_font(&self, name: &CStr) {
+        // Safety:
+        //  Both handles are valid for as long as `self` is, and `name` is a
+        //  nul-terminated string that MiniLibX only reads.
+        unsafe { crate::raw::mlx_set_font(self.mlx.as_raw(), self.as_raw(), name.as_ptr()) };
+    }
+
+    /// Returns the position of the mouse, relative to the window.
+    pub fn mouse_position(&self) -> (i32, i32) {
+        let mut x = 0;
+        let mut y = 0;
+        // Safety:
+        //  Both handles are valid for as long as `self` is, and `x` and `y`
+        //  are local variables.
+        unsafe { crate::raw::mlx_mouse_get_pos(self.mlx.as_raw(), self.as_raw(), &mut x, &mut y) };
+        (x, y)
+    }
+
+    /// Moves the mouse to (`x`, `y`), relative to the window.
+    #[inline]
+    pub fn move_mouse(&self, x: i32, y: i32) {
+        // Safety:
+        //  Both handles are valid for as long as `self` is.
+        unsafe { crate::raw::mlx_mouse_move(self.mlx.as_raw(), self.as_raw(), x, y) };
+    }
+
+    /// Hides the mouse cursor while it is over the window.
+    #[inline]
+    pub fn hide_mouse(&self) {
+        // Safety:
+        //  Both handles are valid for as long as `self` is.
+        unsafe { crate::raw::mlx_mouse_hide(self.mlx.as_raw(), self.as_raw()) };
+    }
+
+    /// Shows the mouse cursor again, after [`Window::hide_mouse`].
+    #[inline]
+    pub fn show_mouse(&self) {
+        // Safety:
+        //  Both handles are valid for as long as `self` is.
+        unsafe { crate::raw::mlx_mouse_show(self.mlx.as_raw(), self.as_raw()) };
     }
 
     /// Clears the window.
     #[inline]
     pub fn clear(&self) {
+        // Safety:
+        //  Both handles are valid for as long as `self` is.
         unsafe { crate::raw::mlx_clear_window(self.mlx().as_raw(), self.as_raw()) };
     }
 
     /// Puts the pixels of an image on this window.
     #[inline]
     pub fn put_image(&self, img: &Image, x: u32, y: u32) {
+        // Safety:
+        //  The handles are valid for as long as `self` and `img` are.
         unsafe {
             crate::raw::mlx_put_image_to_window(
                 self.mlx().as_raw(),
@@ -69,32 +248,46 @@ impl Window {
 
     /// Hooks a function to listen for a specific event on this window.
     ///
-    /// ## Safety
-    ///
-    /// The produced `DynBox` must be kept alive for the lifetime of the
-    /// window.
-    pub unsafe fn hook<'a, H, F>(&self, f: F) -> DynBox<'a>
+    /// The function stays hooked until the returned guard is dropped. It may
+    /// not borrow anything: as the guard can be forgotten, the function may
+    /// be called for as long as the window exists.
+    pub fn hook<H, F>(&self, f: F) -> HookGuard<'_>
     where
-        F: FnMut(H) + 'a,
+        F: FnMut(H) + 'static,
         H: Hook,
     {
         let mut b: Box<F> = Box::new(f);
 
-        crate::raw::mlx_hook(
-            self.as_raw(),
-            H::X_EVENT,
-            H::X_MASK,
-            H::get_callback::<F>(),
-            &mut *b as *mut F as *mut c_void,
-        );
-        DynBox::new(b)
+        // Safety:
+        //  The function is boxed, so its address does not change, and it is
+        //  unhooked before the box is freed. If the guard is forgotten, the
+        //  box is never freed, and the function borrows nothing.
+        unsafe {
+            crate::raw::mlx_hook(
+                self.as_raw(),
+                H::X_EVENT,
+                H::X_MASK,
+                H::get_callback::<F>(),
+                &mut *b as *mut F as *mut c_void,
+            )
+        };
+
+        HookGuard {
+            window: self.handle,
+            x_event: H::X_EVENT,
+            _function: DynBox::new(b),
+            _window: std::marker::PhantomData,
+        }
     }
 }
 
-impl Drop for Window {
+impl Drop for Window<'_> {
     fn drop(&mut self) {
+        // Safety:
+        //  Hooks borrow the window, so none is left, and the handle is not
+        //  used anymore.
         unsafe {
-            crate::raw::mlx_destroy_window(self.mlx, self.handle);
+            crate::raw::mlx_destroy_window(self.mlx.as_raw(), self.handle);
         }
     }
 }
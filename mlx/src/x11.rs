@@ -0,0 +1,87 @@
+//! The parts of X11 and of the private structures of MiniLibX used to do what
+//! MiniLibX does not provide.
+//!
+//! The structures mirror `t_xvar` and `t_win_list`, as defined in `mlx_int.h`
+//! by [minilibx-linux] since `mlx_loop_end` was added in 2020, which is the
+//! version `libmlx.a` is built from. The offsets checked below are the ones
+//! found in the compiled library.
+//!
+//! [minilibx-linux]: https://github.com/42Paris/minilibx-linux/blob/master/mlx_int.h
+
+use std::ffi::c_void;
+use std::mem::offset_of;
+use std::os::raw::{c_int, c_uint, c_ulong};
+
+/// The number of X11 events a window can be hooked to.
+const MLX_MAX_EVENT: usize = 36;
+
+/// `t_event_list`: a function hooked to an event of a window.
+#[repr(C)]
+pub struct EventList {
+    pub mask: c_int,
+    pub hook: *mut c_void,
+    pub param: *mut c_void,
+}
+
+/// `t_win_list`: a window, as returned by `mlx_new_window`.
+#[repr(C)]
+pub struct WinList {
+    pub window: c_ulong,
+    pub gc: *mut c_void,
+    pub next: *mut WinList,
+    pub mouse_hook: *mut c_void,
+    pub key_hook: *mut c_void,
+    pub expose_hook: *mut c_void,
+    pub mouse_param: *mut c_void,
+    pub key_param: *mut c_void,
+    pub expose_param: *mut c_void,
+    pub hooks: [EventList; MLX_MAX_EVENT],
+}
+
+/// `t_xvar`: an instance of MiniLibX, as returned by `mlx_init`.
+#[repr(C)]
+pub struct XVar {
+    pub display: *mut c_void,
+    pub root: c_ulong,
+    pub screen: c_int,
+    pub depth: c_int,
+    pub visual: *mut c_void,
+    pub cmap: c_ulong,
+    pub private_cmap: c_int,
+    pub win_list: *mut WinList,
+    pub loop_hook: *mut c_void,
+    pub loop_param: *mut c_void,
+    pub use_xshm: c_int,
+    pub pshm_format: c_int,
+    pub do_flush: c_int,
+    pub decrgb: [c_int; 6],
+    pub wm_delete_window: c_ulong,
+    pub wm_protocols: c_ulong,
+    pub end_loop: c_int,
+}
+
+// The offsets written by `mlx_new_window`, `mlx_hook`, `mlx_loop_hook` and
+// `mlx_loop_end` on x86-64.
+#[cfg(target_arch = "x86_64")]
+const _: () = {
+    assert!(offset_of!(XVar, win_list) == 0x30);
+    assert!(offset_of!(XVar, loop_hook) == 0x38);
+    assert!(offset_of!(XVar, end_loop) == 0x80);
+    assert!(offset_of!(WinList, hooks) == 0x48);
+    assert!(std::mem::size_of::<EventList>() == 0x18);
+};
+
+extern "C" {
+    pub fn XMoveWindow(display: *mut c_void, window: c_ulong, x: c_int, y: c_int) -> c_int;
+    pub fn XGetGeometry(
+        display: *mut c_void,
+        drawable: c_ulong,
+        root: *mut c_ulong,
+        x: *mut c_int,
+        y: *mut c_int,
+        width: *mut c_uint,
+        height: *mut c_uint,
+        border_width: *mut c_uint,
+        depth: *mut c_uint,
+    ) -> c_int;
+}
@@ -0,0 +1,233 @@
+//! Animates the tiles of the grid.
+
+use std::f32::consts::PI;
+use std::time::{Duration, Instant};
+
+/// How long it takes to flip a tile.
+const FLIP_DURATION: Duration = Duration::from_millis(400);
+/// The delay between the flips of two consecutive tiles of a row.
+const FLIP_STAGGER: Duration = Duration::from_millis(200);
+/// How long a tile grows when a letter is typed.
+const POP_DURATION: Duration = Duration::from_millis(100);
+/// How long a rejected row shakes.
+const SHAKE_DURATION: Duration = Duration::from_millis(400);
+/// How long a tile of the winning row jumps.
+const BOUNCE_DURATION: Duration = Duration::from_millis(500);
+/// The delay between the jumps of two consecutive tiles of the winning row.
+const BOUNCE_STAGGER: Duration = Duration::from_millis(100);
+
+/// Starts slowly and accelerates until the middle, then slows down.
+fn ease_in_out(t: f32) -> f32 {
+    if t < 0.5 {
+        2.0 * t * t
+    } else {
+        1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+    }
+}
+
+/// Starts quickly and slows down.
+fn ease_out(t: f32) -> f32 {
+    1.0 - (1.0 - t).powi(2)
+}
+
+/// An effect that can be applied to a tile.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Effect {
+    /// The tile flips around its horizontal axis, revealing its color.
+    Flip,
+    /// The tile grows for a moment.
+    Pop,
+    /// The tile moves from left to right.
+    Shake,
+    /// The tile jumps.
+    Bounce,
+}
+
+/// An effect applied to a tile of the grid.
+struct Animation {
+    effect: Effect,
+    row: usize,
+    col: usize,
+    start: Instant,
+    duration: Duration,
+}
+
+/// How a tile is drawn at a given time.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Transform {
+    /// The horizontal scale of the tile.
+    pub scale_x: f32,
+    /// The vertical scale of the tile.
+    pub scale_y: f32,
+    /// The offset of the tile, in pixels.
+    pub dx: i32,
+    pub dy: i32,
+    /// Whether the color of the tile is shown.
+    pub revealed: bool,
+}
+
+impl Transform {
+    /// A tile that is drawn as is.
+    pub const IDENTITY: Self = Self {
+        scale_x: 1.0,
+        scale_y: 1.0,
+        dx: 0,
+        dy: 0,
+        revealed: true,
+    };
+}
+
+/// The running animations of the grid.
+///
+/// Animations are defined with the time they start, and evaluated at the time
+/// of the last call to [`Animations::update`].
+pub struct Animations {
+    running: Vec<Animation>,
+    now: Instant,
+    /// When set, no animation is ever started.
+    reduced_motion: bool,
+}
+
+impl Animations {
+    pub fn new(reduced_motion: bool) -> Self {
+        Self {
+            running: Vec::new(),
+            now: Instant::now(),
+            reduced_motion,
+        }
+    }
+
+    /// Sets the current time, and forgets the animations that are over.
+    pub fn update(&mut self, now: Instant) {
+        self.now = now;
+        self.running
+            .retain(|animation| animation.start + animation.duration > now);
+    }
+
+    /// Returns whether an animation is running.
+    #[inline]
+    pub fn is_running(&self) -> bool {
+        !self.running.is_empty()
+    }
+
+    /// Stops every animation.
+    pub fn clear(&mut self) {
+        self.running.clear();
+    }
+
+    /// Delays every animation by `by`, such as after the game was paused.
+    pub fn delay(&mut self, by: Duration) {
+        for animation in &mut self.running {
+            animation.start += by;
+        }
+    }
+
+    /// Sets whether animations are disabled. Disabling them stops the running
+    /// ones.
+    pub fn set_reduced_motion(&mut self, reduced_motion: bool) {
+        self.reduced_motion = reduced_motion;
+        if reduced_motion {
+            self.clear();
+        }
+    }
+
+    fn start(
+        &mut self,
+        effect: Effect,
+        row: usize,
+        col: usize,
+        delay: Duration,
+        duration: Duration,
+    ) {
+        if self.reduced_motion {
+            return;
+        }
+
+        self.running.push(Animation {
+            effect,
+            row,
+            col,
+            start: self.now + delay,
+            duration,
+        });
+    }
+
+    /// Makes the tile at `row` and `col` pop, after a letter was typed in it.
+    pub fn pop(&mut self, row: usize, col: usize) {
+        self.start(Effect::Pop, row, col, Duration::ZERO, POP_DURATION);
+    }
+
+    /// Flips the `len` tiles of `row` one after the other, to reveal the
+    /// correctness of a guess.
+    pub fn flip(&mut self, row: usize, len: usize) {
+        for col in 0..len {
+            self.start(
+                Effect::Flip,
+                row,
+                col,
+                FLIP_STAGGER * col as u32,
+                FLIP_DURATION,
+            );
+        }
+    }
+
+    /// Shakes the `len` tiles of `row`, after a word was rejected.
+    pub fn shake(&mut self, row: usize, len: usize) {
+        for col in 0..len {
+            self.start(Effect::Shake, row, col, Duration::ZERO, SHAKE_DURATION);
+        }
+    }
+
+    /// Makes the `len` tiles of `row` jump one after the other, once they
+    /// are flipped.
+    pub fn bounce(&mut self, row: usize, len: usize) {
+        let flipped = FLIP_STAGGER * len.saturating_sub(1) as u32 + FLIP_DURATION;
+        for col in 0..len {
+            let delay = flipped + BOUNCE_STAGGER * col as u32;
+            self.start(Effect::Bounce, row, col, delay, BOUNCE_DURATION);
+        }
+    }
+
+    /// Returns how the tile at `row` and `col` must be drawn.
+    pub fn tile(&self, row: usize, col: usize) -> Transform {
+        let mut transform = Transform::IDENTITY;
+
+        for animation in &self.running {
+            if animation.row != row || animation.col != col {
+                continue;
+            }
+
+            // The color of a tile is hidden until it is flipped.
+            if self.now < animation.start {
+                if animation.effect == Effect::Flip {
+                    transform.revealed = false;
+                }
+                continue;
+            }
+
+            let t = (self.now - animation.start).as_secs_f32() / animation.duration.as_secs_f32();
+            let t = t.min(1.0);
+            match animation.effect {
+                Effect::Flip => {
+                    let t = ease_in_out(t);
+                    transform.scale_y *= (1.0 - 2.0 * t).abs();
+                    transform.revealed &= t >= 0.5;
+                }
+                Effect::Pop => {
+                    let scale = 1.0 + 0.12 * (PI * ease_out(t)).sin();
+                    transform.scale_x *= scale;
+                    transform.scale_y *= scale;
+                }
+                Effect::Shake => {
+                    let amplitude = 10.0 * (1.0 - t);
+                    transform.dx += (amplitude * (6.0 * PI * t).sin()).round() as i32;
+                }
+                Effect::Bounce => {
+                    transform.dy -= (24.0 * (PI * ease_out(t)).sin()).round() as i32;
+                }
+            }
+        }
+
+        transform
+    }
+}
@@ -0,0 +1,432 @@
+//! The game as it is played, whatever the frontend.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use mlx::{Event, KeyCode};
+
+use crate::export::{self, ExportError};
+use crate::*;
+
+/// How long a message stays on screen.
+const TOAST_DURATION: Duration = Duration::from_millis(1500);
+
+/// How many frames are drawn per second, at most.
+const TARGET_FPS: u32 = 60;
+
+/// How long a frame lasts, at least.
+const FRAME_DURATION: Duration = Duration::from_nanos(1_000_000_000 / TARGET_FPS as u64);
+
+/// What the game shows besides the tiles of the grid.
+///
+/// When any of it changes, the whole game is drawn again.
+#[derive(PartialEq)]
+struct Surroundings {
+    size: (u32, u32),
+    screen: Screen,
+    current_try: usize,
+    state: GameState,
+    hovered: Option<KeyAction>,
+    pressed: Option<KeyAction>,
+    toast: Option<&'static str>,
+}
+
+impl Surroundings {
+    fn new<C: Canvas>(game: &Game, view: &View, canvas: &C) -> Self {
+        Self {
+            size: (canvas.width(), canvas.height()),
+            screen: view.menu.screen,
+            current_try: game.current_try,
+            state: game.state,
+            hovered: view.hovered,
+            pressed: view.pressed,
+            toast: view.toast.as_ref().map(|toast| toast.message),
+        }
+    }
+}
+
+/// Applies a key of the keyboard, or of the on-screen keyboard, to the game,
+/// and animates the result.
+fn press(game: &mut Game, view: &mut View, action: KeyAction) {
+    let row = game.current_try;
+    let len = game.rules.word_size;
+    let animations = &mut view.animations;
+
+    match action {
+        KeyAction::Letter(letter) => {
+            if game.type_letter(letter) {
+                animations.pop(row, game.cursor - 1);
+            }
+        }
+        KeyAction::Enter => match game.confirm_word() {
+            Confirmation::Restarted => animations.clear(),
+            Confirmation::Rejected(rejection) => {
+                animations.shake(row, len);
+                view.toast = Some(Toast {
+                    message: rejection_message(rejection),
+                    until: Instant::now() + TOAST_DURATION,
+                });
+            }
+            Confirmation::Accepted => {
+                animations.flip(row, len);
+                if game.state == GameState::Won {
+                    animations.bounce(row, len);
+                }
+                view.stats.record(game);
+            }
+        },
+        KeyAction::Backspace => game.cancel_letter(),
+    }
+}
+
+/// Applies a setting changed from the settings screen, and saves it to the
+/// configuration file.
+fn apply(setting: Setting, game: &mut Game, view: &mut View) {
+    let config = &view.menu.config;
+    match setting {
+        // The winning word depends on the mode, so a new game is started.
+        Setting::Mode => {
+            seed_random(seed(config));
+            game.restart();
+            view.animations.clear();
+        }
+        Setting::Hard => game.rules.hard = config.rules.hard,
+        Setting::Theme => view.theme = config.theme,
+        Setting::Layout => view.layout = config.layout(),
+        Setting::ReducedMotion => view.animations.set_reduced_motion(config.reduced_motion),
+    }
+
+    if let Err(err) = config.save(&[setting.key()]) {
+        eprintln!("warning: {}", err);
+    }
+}
+
+/// A game being played, along with what is needed to show it.
+pub struct App<C> {
+    game: Game,
+    view: View,
+    input: TextInput,
+    /// When the frontend lost the focus. Animations and messages are frozen
+    /// until it gets it back.
+    paused_at: Option<Instant>,
+    /// The canvas the game is drawn on.
+    canvas: C,
+    /// The images, with the colors they were loaded with.
+    sprites: Images<C>,
+    /// The images, with the colors of `theme`.
+    images: Images<C>,
+    theme: &'static Theme,
+    /// Whether a screenshot was asked for, to be taken once the frame is
+    /// drawn.
+    screenshot_pending: bool,
+    /// Whether the whole game must be drawn again, whatever changed.
+    redraw: bool,
+    /// What the game showed besides the grid when it was last drawn.
+    surroundings: Option<Surroundings>,
+    /// How the tiles of the grid were last drawn.
+    tiles: Vec<TileState>,
+    /// Whether animations were running when the game was last drawn.
+    animating: bool,
+}
+
+impl<C: Canvas> App<C> {
+    /// Creates a canvas of the size of `frontend`, to draw the game on with
+    /// the images `sprites`.
+    pub fn new<F>(frontend: &F, game: Game, view: View, sprites: Images<C>) -> Self
+    where
+        F: Frontend<Canvas = C>,
+    {
+        let (width, height) = frontend.size();
+        let theme = view.theme;
+        let images = sprites.themed(frontend, theme);
+
+        Self {
+            game,
+            view,
+            input: TextInput::default(),
+            paused_at: None,
+            canvas: frontend.create_canvas(width, height),
+            sprites,
+            images,
+            theme,
+            screenshot_pending: false,
+            redraw: true,
+            surroundings: None,
+            tiles: Vec::new(),
+            animating: false,
+        }
+    }
+
+    #[inline]
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    /// Writes the canvas, as it was last drawn, to the image file `path`.
+    pub fn save(&self, path: &Path) -> Result<(), ExportError> {
+        export::save(&self.canvas, path)
+    }
+
+    /// Saves the canvas to the file configured for screenshots, and tells
+    /// whether it worked.
+    fn screenshot(&mut self) {
+        let path = self.view.menu.config.screenshot.clone().unwrap_or_else(|| {
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs());
+            PathBuf::from(format!("wordle-{}.png", now))
+        });
+
+        let message = match self.save(&path) {
+            Ok(()) => "Screenshot saved",
+            Err(err) => {
+                eprintln!("warning: {}", err);
+                "Cannot save the screenshot"
+            }
+        };
+        self.view.toast = Some(Toast {
+            message,
+            until: Instant::now() + TOAST_DURATION,
+        });
+    }
+
+    /// Handles `event`, returning the resulting command, if any.
+    fn handle<F>(&mut self, event: Event, frontend: &F) -> Option<Command>
+    where
+        F: Frontend<Canvas = C>,
+    {
+        let game = &mut self.game;
+        let view = &mut self.view;
+
+        match event {
+            Event::Destroy => Some(Command::Quit),
+            // What was shown in the window was lost.
+            Event::Expose => {
+                self.redraw = true;
+                None
+            }
+            Event::KeyPress(keycode) if self.input.is_help(keycode) => {
+                match view.menu.screen {
+                    Screen::Help => view.menu.open(Screen::Playing),
+                    _ => view.menu.open(Screen::Help),
+                }
+                None
+            }
+            Event::KeyPress(KeyCode::F12) => {
+                self.screenshot_pending = true;
+                None
+            }
+            Event::KeyPress(KeyCode::F2) => {
+                Setting::Theme.change(&mut view.menu.config, 1);
+                Some(Command::Changed(Setting::Theme))
+            }
+            Event::KeyPress(keycode) if view.menu.screen != Screen::Playing => {
+                view.menu.key_press(keycode)
+            }
+            Event::KeyPress(KeyCode::ESCAPE) => {
+                view.menu.open(Screen::Menu);
+                None
+            }
+            Event::KeyPress(keycode) => {
+                if let Some(action) = self.input.key_press(keycode, game.language) {
+                    press(game, view, action);
+                }
+                None
+            }
+            Event::KeyRelease(keycode) => {
+                self.input.key_release(keycode);
+                None
+            }
+            Event::MouseMove { x, y } => {
+                if view.menu.screen != Screen::Playing {
+                    if let Some(index) = screen_row_at(view, x, y) {
+                        view.menu.selected = index;
+                    }
+                } else {
+                    view.hovered = key_at(game, view, x, y);
+                }
+                None
+            }
+            // Only the left button presses keys of the on-screen keyboard.
+            Event::MousePress { button: 1, x, y } => {
+                view.pressed = key_at(game, view, x, y);
+                None
+            }
+            Event::MouseRelease { button: 1, x, y } if view.menu.screen != Screen::Playing => {
+                screen_row_at(view, x, y).and_then(|i| view.menu.activate(i))
+            }
+            Event::MouseRelease { button: 1, x, y } => {
+                // The key is only pressed if the button is released above it.
+                if let Some(action) = view.pressed.take() {
+                    if key_at(game, view, x, y) == Some(action) {
+                        press(game, view, action);
+                    }
+                }
+                view.hovered = key_at(game, view, x, y);
+                None
+            }
+            Event::MouseLeave => {
+                view.hovered = None;
+                view.pressed = None;
+                None
+            }
+            Event::FocusOut => {
+                self.paused_at = Some(Instant::now());
+                None
+            }
+            Event::FocusIn => {
+                if let Some(start) = self.paused_at.take() {
+                    let paused = start.elapsed();
+                    view.animations.delay(paused);
+                    if let Some(toast) = &mut view.toast {
+                        toast.until += paused;
+                    }
+                }
+
+                // The mouse may have moved while another window was focused.
+                if view.menu.screen == Screen::Playing {
+                    if let Some((x, y)) = frontend.mouse_position() {
+                        view.hovered = key_at(game, view, x, y);
+                    }
+                }
+                None
+            }
+            // The canvas and the geometry follow the size of the window.
+            Event::Configure => {
+                let (width, height) = frontend.size();
+                if width >= MIN_WINDOW_SIZE
+                    && height >= MIN_WINDOW_SIZE
+                    && (width, height) != (self.canvas.width(), self.canvas.height())
+                {
+                    self.canvas = frontend.create_canvas(width, height);
+                    view.geometry = Geometry::new(width, height, &game.rules);
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Draws on the canvas what changed since it was last drawn, and returns
+    /// whether anything did.
+    fn draw(&mut self) -> bool {
+        let animating = self.view.animations.is_running();
+        // Tiles also change when the last animation ends.
+        let tiles_changed = std::mem::take(&mut self.game.dirty) || animating || self.animating;
+        self.animating = animating;
+
+        let game = &self.game;
+        let view = &self.view;
+
+        let surroundings = Surroundings::new(game, view, &self.canvas);
+        let redraw =
+            std::mem::take(&mut self.redraw) || self.surroundings.as_ref() != Some(&surroundings);
+        self.surroundings = Some(surroundings);
+
+        if !redraw && !tiles_changed {
+            return false;
+        }
+
+        let tiles = tile_states(game, view);
+        let changed = if redraw || tiles.len() != self.tiles.len() {
+            None
+        } else {
+            let len = game.rules.word_size;
+            let mut area = None;
+            for (i, (old, new)) in self.tiles.iter().zip(&tiles).enumerate() {
+                if old != new {
+                    let (row, col) = (i / len, i % len);
+                    let tile = tile_area(view, row, col, old).union(tile_area(view, row, col, new));
+                    area = Some(area.map_or(tile, |area: Rect| area.union(tile)));
+                }
+            }
+            match area {
+                Some(area) => Some(area),
+                None => return false,
+            }
+        };
+        self.tiles = tiles;
+
+        let canvas = &mut self.canvas;
+        if !changed.is_some_and(|area| draw_grid_area(game, view, canvas, &self.images, area)) {
+            draw(game, view, canvas, &self.images);
+        }
+        true
+    }
+
+    /// Handles the pending events of `frontend`, then draws the game on it if
+    /// it changed, and waits for the next frame.
+    ///
+    /// Returns whether the game goes on, and whether it moves on its own.
+    pub fn frame<F>(&mut self, frontend: &mut F) -> Frame
+    where
+        F: Frontend<Canvas = C>,
+    {
+        let start = Instant::now();
+
+        while let Some(event) = frontend.poll() {
+            // The screens are always drawn again, as they have their own state.
+            if self.view.menu.screen != Screen::Playing {
+                self.redraw = true;
+            }
+            match self.handle(event, frontend) {
+                Some(Command::Quit) => return Frame::Quit,
+                Some(Command::Changed(setting)) => {
+                    apply(setting, &mut self.game, &mut self.view);
+                    self.redraw = true;
+                }
+                None => (),
+            }
+            // The events that follow are handled once the screenshot shows
+            // the game as it was when it was asked for.
+            if self.screenshot_pending {
+                break;
+            }
+        }
+
+        let view = &mut self.view;
+        if self.paused_at.is_none() {
+            let now = Instant::now();
+            view.animations.update(now);
+            if view.toast.as_ref().is_some_and(|toast| toast.until <= now) {
+                view.toast = None;
+            }
+        }
+        if !std::ptr::eq(self.theme, view.theme) {
+            self.theme = view.theme;
+            self.images = self.sprites.themed(frontend, self.theme);
+        }
+
+        if self.draw() {
+            frontend.present(&self.canvas);
+        }
+        if std::mem::take(&mut self.screenshot_pending) {
+            self.screenshot();
+        }
+        frontend.wait(start + FRAME_DURATION);
+
+        let view = &self.view;
+        if self.paused_at.is_none() && (view.animations.is_running() || view.toast.is_some()) {
+            Frame::Moving
+        } else {
+            Frame::Idle
+        }
+    }
+}
+
+#[cfg(test)]
+impl App<Framebuffer> {
+    /// Draws the whole game on a new canvas, to compare with the one drawn
+    /// frame after frame.
+    pub fn draw_all(&self) -> (Framebuffer, &Framebuffer) {
+        let (width, height) = (self.canvas.width(), self.canvas.height());
+        let mut canvas = Framebuffer::new(width, height);
+        draw(&self.game, &self.view, &mut canvas, &self.images);
+        (canvas, &self.canvas)
+    }
+
+    #[inline]
+    pub fn is_animating(&self) -> bool {
+        self.view.animations.is_running()
+    }
+}
@@ -0,0 +1,104 @@
+//! Measures how much of a CPU core the game uses, drawing every frame in
+//! full as fast as possible, as it used to, and drawing only what changed at
+//! a steady rate, when nothing happens and while letters are typed.
+//!
+//! Run with `cargo test --release bench -- --ignored --nocapture`.
+
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::*;
+
+/// How long each way of drawing is measured.
+const DURATION: Duration = Duration::from_secs(2);
+
+/// Returns the CPU time used by the current thread.
+fn cpu_time() -> Duration {
+    // Safety:
+    //  `rusage` is a plain C structure, which `getrusage` fills.
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    unsafe { libc::getrusage(libc::RUSAGE_THREAD, &mut usage) };
+    let time = |t: libc::timeval| Duration::new(t.tv_sec as u64, t.tv_usec as u32 * 1000);
+    time(usage.ru_utime) + time(usage.ru_stime)
+}
+
+/// Calls `frame` repeatedly for [`DURATION`], and returns the share of the
+/// time spent using the CPU, along with the number of frames.
+fn measure(mut frame: impl FnMut()) -> (f64, u32) {
+    let (start, cpu) = (Instant::now(), cpu_time());
+    let mut frames = 0;
+    while start.elapsed() < DURATION {
+        frame();
+        frames += 1;
+    }
+    let usage = (cpu_time() - cpu).as_secs_f64() / start.elapsed().as_secs_f64();
+    (usage, frames)
+}
+
+#[test]
+#[ignore = "measures the CPU usage for a few seconds"]
+fn cpu_usage() {
+    let config = Config::default();
+    let words = ["CRANE", "SLATE"].map(|w| w.chars().map(|c| ENGLISH.letter(c).unwrap()).collect());
+    let game = Game::new(
+        Rules::default(),
+        &ENGLISH,
+        words.to_vec(),
+        words[..1].to_vec(),
+    );
+    let (width, height) = window_size(&game.rules, 1.0);
+    let view = View::new(&config, width, height);
+
+    let mut frontend = Headless::new(width, height);
+    let assets = AssetSource::Dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("assets"));
+    let sprites = Images::load(&frontend, &assets).unwrap();
+
+    // Before: the whole game is drawn again as soon as it was drawn.
+    let images = sprites.themed(&frontend, view.theme);
+    let mut canvas = frontend.create_canvas(width, height);
+    let (before, before_frames) = measure(|| {
+        draw(&game, &view, &mut canvas, &images);
+        frontend.present(&canvas);
+    });
+
+    let mut app = App::new(&frontend, game, view, sprites);
+
+    // Nothing happens: the game was drawn once and stays the same.
+    let (idle, idle_frames) = measure(|| {
+        app.frame(&mut frontend);
+    });
+
+    // A letter is typed or erased every half second, and animated.
+    let mut typed = Instant::now();
+    let keys = key_events(b"s\x7f");
+    let mut keys = keys.iter().cycle();
+    let (typing, typing_frames) = measure(|| {
+        if typed.elapsed() >= Duration::from_millis(500) {
+            typed = Instant::now();
+            // Each key is pressed, then released.
+            frontend.push(*keys.next().unwrap());
+            frontend.push(*keys.next().unwrap());
+        }
+        app.frame(&mut frontend);
+    });
+
+    println!(
+        "before: {:5.1}% of a core, {:5} frames in {:?}",
+        before * 100.0,
+        before_frames,
+        DURATION
+    );
+    println!(
+        "idle:   {:5.1}% of a core, {:5} frames in {:?}",
+        idle * 100.0,
+        idle_frames,
+        DURATION
+    );
+    println!(
+        "typing: {:5.1}% of a core, {:5} frames in {:?}",
+        typing * 100.0,
+        typing_frames,
+        DURATION
+    );
+    assert!(idle < before && typing < before);
+}
@@ -0,0 +1,116 @@
+//! The surfaces the game is drawn on.
+
+use mlx::{Color, Rect};
+
+/// A surface of pixels that can be drawn on.
+///
+/// This is implemented by the images of the MiniLibX, and by [`Framebuffer`]
+/// for the frontends that do not use it.
+pub use mlx::Canvas;
+
+/// A view of a canvas on which only the pixels of an area are drawn.
+///
+/// Drawing on the rest of the canvas is silently ignored, so that a part of
+/// a scene can be drawn again without changing the rest.
+pub struct Clip<'a, C> {
+    canvas: &'a mut C,
+    area: Rect,
+}
+
+impl<'a, C: Canvas> Clip<'a, C> {
+    #[inline]
+    pub fn new(canvas: &'a mut C, area: Rect) -> Self {
+        Self { canvas, area }
+    }
+
+    /// Returns whether the pixel at (`x`, `y`) is inside the area.
+    #[inline]
+    fn contains(&self, x: u32, y: u32) -> bool {
+        let area = self.area;
+        (area.x..area.x + area.width).contains(&x) && (area.y..area.y + area.height).contains(&y)
+    }
+}
+
+impl<C: Canvas> Canvas for Clip<'_, C> {
+    #[inline]
+    fn width(&self) -> u32 {
+        self.canvas.width()
+    }
+
+    #[inline]
+    fn height(&self) -> u32 {
+        self.canvas.height()
+    }
+
+    #[inline]
+    fn get_pixel(&self, x: u32, y: u32) -> Option<Color> {
+        self.canvas.get_pixel(x, y)
+    }
+
+    #[inline]
+    fn set_pixel(&mut self, x: u32, y: u32, color: Color) {
+        if self.contains(x, y) {
+            self.canvas.set_pixel(x, y, color);
+        }
+    }
+
+    fn fill_rect(&mut self, rect: Rect, color: Color) {
+        if let Some(rect) = rect.intersection(self.area) {
+            self.canvas.fill_rect(rect, color);
+        }
+    }
+}
+
+/// A canvas stored in memory, one [`Color`] per pixel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Framebuffer {
+    width: u32,
+    height: u32,
+    pixels: Vec<Color>,
+}
+
+impl Framebuffer {
+    /// Creates a canvas of `width` by `height` black pixels, like a new
+    /// image of the MiniLibX.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![Color::BLACK; (width * height) as usize],
+        }
+    }
+
+    /// Returns the index of the pixel at (`x`, `y`), if it is inside the
+    /// canvas.
+    #[inline]
+    fn index(&self, x: u32, y: u32) -> Option<usize> {
+        (x < self.width && y < self.height).then_some((y * self.width + x) as usize)
+    }
+}
+
+impl Canvas for Framebuffer {
+    #[inline]
+    fn width(&self) -> u32 {
+        self.width
+    }
+
+    #[inline]
+    fn height(&self) -> u32 {
+        self.height
+    }
+
+    #[inline]
+    fn get_pixel(&self, x: u32, y: u32) -> Option<Color> {
+        self.index(x, y).map(|index| self.pixels[index])
+    }
+
+    fn set_pixel(&mut self, x: u32, y: u32, color: Color) {
+        let index = self.index(x, y).unwrap_or_else(|| {
+            panic!(
+                "pixel ({}, {}) is outside of a {}x{} canvas",
+                x, y, self.width, self.height
+            )
+        });
+        self.pixels[index] = color;
+    }
+}
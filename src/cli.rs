@@ -0,0 +1,373 @@
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+use crate::config::parse_language;
+use crate::language::{Language, ENGLISH};
+
+/// A command-line flag that maps to a setting of the configuration file.
+struct Flag {
+    /// The name of the flag, without the leading dashes. This is also the
+    /// name of the setting it overrides.
+    name: &'static str,
+    /// The name of the flag's value, or [`None`] if the flag takes no value.
+    value: Option<&'static str>,
+    /// A short description of the flag.
+    help: &'static str,
+}
+
+const FLAGS: &[Flag] = &[
+    Flag {
+        name: "words",
+        value: Some("PATH"),
+        help: "the list of words accepted as guesses",
+    },
+    Flag {
+        name: "answers",
+        value: Some("PATH"),
+        help: "the list of words that can be choosen as the answer",
+    },
+    Flag {
+        name: "assets",
+        value: Some("DIR"),
+        help: "the directory containing the images",
+    },
+    Flag {
+        name: "lang",
+        value: Some("LANG"),
+        help: "the language of the words: 'en', 'fr', 'es' or 'de' (only English words are \
+               shipped: give the others with --words)",
+    },
+    Flag {
+        name: "layout",
+        value: Some("LAYOUT"),
+        help: "the layout of the on-screen keyboard, or 'auto'",
+    },
+    Flag {
+        name: "mode",
+        value: Some("MODE"),
+        help: "how answers are choosen: 'random' or 'daily'",
+    },
+    Flag {
+        name: "length",
+        value: Some("N"),
+        help: "the number of letters in each word",
+    },
+    Flag {
+        name: "tries",
+        value: Some("N"),
+        help: "the number of guesses allowed",
+    },
+    Flag {
+        name: "hard",
+        value: None,
+        help: "revealed hints must be used in subsequent guesses",
+    },
+    Flag {
+        name: "theme",
+        value: Some("THEME"),
+        help: "the colors of the game, also changed with F2",
+    },
+    Flag {
+        name: "reduced-motion",
+        value: None,
+        help: "show feedback instantly instead of animating it",
+    },
+    Flag {
+        name: "scale",
+        value: Some("SCALE"),
+        help: "the scale of the window, such as 2 on HiDPI screens, or 'auto'",
+    },
+    Flag {
+        name: "size",
+        value: Some("WxH"),
+        help: "the size of the window in pixels, or 'auto' to fit the board",
+    },
+    Flag {
+        name: "seed",
+        value: Some("N"),
+        help: "the seed of the random number generator",
+    },
+    Flag {
+        name: "ui",
+        value: Some("UI"),
+        help: "the user interface to use: 'mlx', 'terminal' or 'headless'",
+    },
+    Flag {
+        name: "screenshot",
+        value: Some("PATH"),
+        help: "the PNG or PPM file the board is saved to with F12",
+    },
+];
+
+/// The arguments passed to the program.
+#[derive(Default)]
+pub struct Args {
+    /// The configuration file to use instead of the default one.
+    pub config: Option<PathBuf>,
+    /// Whether the configuration file should be ignored.
+    pub no_config: bool,
+    /// The image file a finished game is rendered to, without showing it.
+    pub render_result: Option<PathBuf>,
+    /// The settings overriden on the command-line, as `(key, value)` pairs.
+    pub settings: Vec<(&'static str, String)>,
+}
+
+/// A subcommand of `wordle dict`.
+pub enum DictCommand {
+    /// Prints the help message of `wordle dict`.
+    Help,
+    /// Checks the lengths, characters, duplicates and order of a word list, and
+    /// that every answer is an accepted guess.
+    Check {
+        words: PathBuf,
+        answers: Option<PathBuf>,
+        length: Option<usize>,
+    },
+    /// Prints the frequency of each letter at each position.
+    Stats { words: PathBuf },
+    /// Prints the sorted union of multiple word lists.
+    Merge { lists: Vec<PathBuf> },
+    /// Prints the words that were removed from or added to a word list.
+    Diff { old: PathBuf, new: PathBuf },
+    /// Prints the words of a given length.
+    Filter { words: PathBuf, length: usize },
+}
+
+/// What the program has been asked to do.
+pub enum Action {
+    /// Print the help message.
+    Help,
+    /// Print the version of the program.
+    Version,
+    /// Play the game.
+    Play(Args),
+    /// Run a word list maintenance command.
+    Dict(DictCommand, &'static Language),
+}
+
+/// Parses the arguments of `wordle dict`.
+fn parse_dict(
+    args: impl IntoIterator<Item = OsString>,
+) -> Result<(DictCommand, &'static Language), String> {
+    let mut args = args.into_iter().map(PathBuf::from);
+    let mut language: &'static Language = &ENGLISH;
+
+    let command = match args.next() {
+        Some(command) => command.to_string_lossy().into_owned(),
+        None => return Ok((DictCommand::Help, language)),
+    };
+
+    let mut paths = Vec::new();
+    let mut answers = None;
+    let mut length = None;
+
+    while let Some(arg) = args.next() {
+        match arg.to_str() {
+            Some("-h" | "--help") => return Ok((DictCommand::Help, language)),
+            Some("--lang") => {
+                let value = args.next().ok_or("missing value for '--lang'")?;
+                language = parse_language(&value.to_string_lossy())?;
+            }
+            Some("--answers") => {
+                answers = Some(args.next().ok_or("missing value for '--answers'")?);
+            }
+            Some("--length") => {
+                let value = args.next().ok_or("missing value for '--length'")?;
+                let value = value.to_string_lossy();
+                length = Some(
+                    value
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|&n| n != 0)
+                        .ok_or_else(|| format!("invalid length '{}'", value))?,
+                );
+            }
+            Some(flag) if flag.starts_with("--") => {
+                return Err(format!("unknown flag '{}'", flag));
+            }
+            _ => paths.push(arg),
+        }
+    }
+
+    let flag_error = |flag: &str| format!("'{}' does not accept '{}'", command, flag);
+    let single_path = |paths: Vec<PathBuf>| -> Result<PathBuf, String> {
+        match <[PathBuf; 1]>::try_from(paths) {
+            Ok([path]) => Ok(path),
+            Err(_) => Err(format!("'{}' expects a single word list", command)),
+        }
+    };
+
+    let command = match command.as_str() {
+        "help" => Ok(DictCommand::Help),
+        "check" => Ok(DictCommand::Check {
+            words: single_path(paths)?,
+            answers,
+            length,
+        }),
+        _ if answers.is_some() => Err(flag_error("--answers")),
+        "stats" if length.is_none() => Ok(DictCommand::Stats {
+            words: single_path(paths)?,
+        }),
+        "merge" if length.is_none() => {
+            if paths.is_empty() {
+                return Err("'merge' expects at least one word list".into());
+            }
+            Ok(DictCommand::Merge { lists: paths })
+        }
+        "diff" if length.is_none() => match <[PathBuf; 2]>::try_from(paths) {
+            Ok([old, new]) => Ok(DictCommand::Diff { old, new }),
+            Err(_) => Err("'diff' expects two word lists".into()),
+        },
+        "stats" | "merge" | "diff" => Err(flag_error("--length")),
+        "filter" => Ok(DictCommand::Filter {
+            words: single_path(paths)?,
+            length: length.ok_or("'filter' requires '--length'")?,
+        }),
+        _ => Err(format!("unknown dict command '{}'", command)),
+    }?;
+
+    Ok((command, language))
+}
+
+/// Parses the command-line arguments (without the program name).
+pub fn parse(args: impl IntoIterator<Item = OsString>) -> Result<Action, String> {
+    let mut result = Args::default();
+    let mut args = args.into_iter().peekable();
+
+    if args.peek().is_some_and(|arg| arg == "dict") {
+        args.next();
+        let (command, language) = parse_dict(args)?;
+        return Ok(Action::Dict(command, language));
+    }
+
+    while let Some(arg) = args.next() {
+        let arg = arg
+            .into_string()
+            .map_err(|arg| format!("invalid argument '{}'", arg.to_string_lossy()))?;
+
+        let (name, inline_value) = match arg.strip_prefix("--") {
+            Some(rest) => match rest.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_owned())),
+                None => (rest, None),
+            },
+            None if arg == "-h" => ("help", None),
+            None if arg == "-V" => ("version", None),
+            None => return Err(format!("unexpected argument '{}'", arg)),
+        };
+
+        let mut value = |takes_value: bool| -> Result<Option<String>, String> {
+            match (takes_value, inline_value.clone()) {
+                (true, Some(value)) => Ok(Some(value)),
+                (true, None) => match args.next() {
+                    Some(value) => value
+                        .into_string()
+                        .map(Some)
+                        .map_err(|_| format!("invalid value for '--{}'", name)),
+                    None => Err(format!("missing value for '--{}'", name)),
+                },
+                (false, Some(_)) => Err(format!("'--{}' does not take a value", name)),
+                (false, None) => Ok(None),
+            }
+        };
+
+        match name {
+            "help" => return Ok(Action::Help),
+            "version" => return Ok(Action::Version),
+            "config" => result.config = value(true)?.map(PathBuf::from),
+            "no-config" => {
+                value(false)?;
+                result.no_config = true;
+            }
+            "render-result" => result.render_result = value(true)?.map(PathBuf::from),
+            _ => {
+                let flag = FLAGS
+                    .iter()
+                    .find(|flag| flag.name == name)
+                    .ok_or_else(|| format!("unknown flag '--{}'", name))?;
+
+                let value = value(flag.value.is_some())?.unwrap_or_else(|| "true".into());
+                result.settings.push((flag.name, value));
+            }
+        }
+    }
+
+    Ok(Action::Play(result))
+}
+
+/// Prints the help message on the standard output.
+pub fn print_help() {
+    println!("Usage: wordle [OPTIONS]");
+    println!("       wordle dict <COMMAND>");
+    println!();
+    println!("Options:");
+
+    let mut lines: Vec<(String, &str)> = FLAGS
+        .iter()
+        .map(|flag| match flag.value {
+            Some(value) => (format!("--{} <{}>", flag.name, value), flag.help),
+            None => (format!("--{}", flag.name), flag.help),
+        })
+        .collect();
+    lines.push(("--config <PATH>".into(), "the configuration file to use"));
+    lines.push(("--no-config".into(), "ignore the configuration file"));
+    lines.push((
+        "--render-result <PATH>".into(),
+        "play the keys of the standard input, and save the result as an image",
+    ));
+    lines.push(("-h, --help".into(), "print this help message"));
+    lines.push(("-V, --version".into(), "print the version"));
+
+    let width = lines.iter().map(|(flag, _)| flag.len()).max().unwrap_or(0);
+    for (flag, help) in lines {
+        println!("  {:width$}  {}", flag, help, width = width);
+    }
+
+    println!();
+    println!("Languages:");
+    for language in crate::language::LANGUAGES {
+        println!("  {}  {}", language.code, language.name);
+    }
+
+    println!();
+    println!("Keyboard layouts:");
+    for layout in crate::keyboard::LAYOUTS {
+        println!("  {}", layout.name);
+    }
+
+    println!();
+    println!("Themes:");
+    for theme in crate::theme::THEMES {
+        println!("  {}", theme.name);
+    }
+    println!();
+    println!("Settings are also read from the configuration file, as `key = value` lines");
+    println!("named after the options above. Command-line options take precedence.");
+    if let Some(path) = crate::config::Config::default_path() {
+        println!("The default configuration file is '{}'.", path.display());
+    }
+
+    println!();
+    println!("When not given explicitly, the word list and the images are looked for in");
+    println!(
+        "${}, next to the executable, and in the XDG data directories.",
+        crate::resources::DATA_DIR_VAR
+    );
+}
+
+/// Prints the help message of `wordle dict` on the standard output.
+pub fn print_dict_help() {
+    println!("Usage: wordle dict <COMMAND> [--lang <LANG>]");
+    println!();
+    println!("Commands:");
+    println!("  check <WORDS> [--answers <PATH>] [--length <N>]");
+    println!("      check the lengths, characters, duplicates and order of a list,");
+    println!("      and that every answer is in the list");
+    println!("  stats <WORDS>");
+    println!("      print the frequency of each letter at each position");
+    println!("  merge <WORDS>...");
+    println!("      print the sorted union of the lists");
+    println!("  diff <OLD> <NEW>");
+    println!("      print the words removed from (-) and added to (+) a list");
+    println!("  filter --length <N> <WORDS>");
+    println!("      print the words of a list that have N letters");
+}
@@ -0,0 +1,459 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::game::Rules;
+use crate::keyboard::{Layout, LAYOUTS};
+use crate::language::{Language, ENGLISH, LANGUAGES};
+use crate::theme::{Theme, LIGHT, THEMES};
+
+/// The way the winning words are choosen.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Mode {
+    /// Winning words are choosen randomly.
+    Random,
+    /// Winning words are choosen from the current date, so that every player
+    /// gets the same words on a given day.
+    Daily,
+}
+
+/// The user interface used to play the game.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Ui {
+    /// A window opened through the MiniLibX.
+    Mlx,
+    /// The terminal the game is started from.
+    Terminal,
+    /// Nothing is shown, and the keys are read from the standard input.
+    Headless,
+}
+
+/// The settings of the game.
+///
+/// Values are read from the configuration file, then overriden by the
+/// command-line arguments.
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// The list of words that are accepted as guesses. When [`None`], the
+    /// default list of the language is looked for in the data directories.
+    pub words: Option<PathBuf>,
+    /// The list of words that can be choosen as the winning word. When [`None`],
+    /// `words` is used instead.
+    pub answers: Option<PathBuf>,
+    /// The directory from which images are loaded. When [`None`], `assets` is
+    /// looked for in the data directories.
+    pub assets: Option<PathBuf>,
+    /// The language of the words.
+    pub language: &'static Language,
+    /// The layout of the on-screen keyboard. When [`None`], the default layout
+    /// of the language is used.
+    pub layout: Option<&'static Layout>,
+    /// The way winning words are choosen.
+    pub mode: Mode,
+    /// The colors the game is drawn with.
+    pub theme: &'static Theme,
+    /// Whether feedback is shown instantly instead of being animated.
+    pub reduced_motion: bool,
+    /// Whether the help screen is shown when the game starts. It is only
+    /// shown once, on the first launch.
+    pub show_help: bool,
+    /// The scale of the window, relative to the size of the images. When
+    /// [`None`], it is guessed from the environment.
+    pub scale: Option<f32>,
+    /// The size of the window. When [`None`], it is computed from the rules
+    /// and the scale.
+    pub window_size: Option<(u32, u32)>,
+    /// The rules of the game.
+    pub rules: Rules,
+    /// The seed of the random number generator. When [`None`], a seed is
+    /// derived from the current time (or date in [`Mode::Daily`]).
+    pub seed: Option<u32>,
+    /// The user interface to use.
+    pub ui: Ui,
+    /// The file the canvas is written to when F12 is pressed. When [`None`],
+    /// a file named after the current time is written to the current
+    /// directory.
+    pub screenshot: Option<PathBuf>,
+    /// The file the settings changed while playing are saved to. When
+    /// [`None`], they are not saved.
+    pub path: Option<PathBuf>,
+    /// The image file the finished game is saved to, when the game is played
+    /// without being shown. This is only set on the command line.
+    pub render_result: Option<PathBuf>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            words: None,
+            answers: None,
+            assets: None,
+            language: &ENGLISH,
+            layout: None,
+            mode: Mode::Random,
+            theme: &LIGHT,
+            reduced_motion: false,
+            show_help: true,
+            scale: None,
+            window_size: None,
+            rules: Rules::default(),
+            seed: None,
+            ui: Ui::Mlx,
+            screenshot: None,
+            path: None,
+            render_result: None,
+        }
+    }
+}
+
+/// The maximum number of letters in a word.
+pub const MAX_WORD_SIZE: usize = 12;
+/// The maximum number of tries the player can be given.
+pub const MAX_TRIES: usize = 10;
+/// The range of accepted window scales.
+const SCALES: std::ops::RangeInclusive<f32> = 0.25..=8.0;
+/// The minimum width and height of the window.
+pub const MIN_WINDOW_SIZE: u32 = 100;
+
+/// An error that occured whilst reading the configuration file.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The file could not be read.
+    Io(PathBuf, std::io::Error),
+    /// The file could not be written.
+    Write(PathBuf, std::io::Error),
+    /// A line of the file is invalid.
+    Syntax {
+        path: PathBuf,
+        line: usize,
+        message: String,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io(path, err) => write!(f, "failed to read '{}': {}", path.display(), err),
+            Self::Write(path, err) => write!(f, "failed to write '{}': {}", path.display(), err),
+            Self::Syntax {
+                path,
+                line,
+                message,
+            } => write!(f, "{}:{}: {}", path.display(), line, message),
+        }
+    }
+}
+
+fn parse_number(value: &str, min: usize, max: usize) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(n) if (min..=max).contains(&n) => Ok(n),
+        _ => Err(format!(
+            "expected a number between {} and {}, found '{}'",
+            min, max, value
+        )),
+    }
+}
+
+/// Finds the language named `code`.
+pub fn parse_language(code: &str) -> Result<&'static Language, String> {
+    Language::from_code(code).ok_or_else(|| {
+        let codes: Vec<&str> = LANGUAGES.iter().map(|lang| lang.code).collect();
+        format!(
+            "unknown language '{}' (expected one of: {})",
+            code,
+            codes.join(", ")
+        )
+    })
+}
+
+fn parse_scale(value: &str) -> Result<f32, String> {
+    match value.parse() {
+        Ok(scale) if SCALES.contains(&scale) => Ok(scale),
+        _ => Err(format!(
+            "expected a scale between {} and {}, found '{}'",
+            SCALES.start(),
+            SCALES.end(),
+            value
+        )),
+    }
+}
+
+fn parse_window_size(value: &str) -> Result<(u32, u32), String> {
+    let size = value
+        .split_once('x')
+        .and_then(|(width, height)| Some((width.parse().ok()?, height.parse().ok()?)));
+
+    match size {
+        Some((width, height)) if width >= MIN_WINDOW_SIZE && height >= MIN_WINDOW_SIZE => {
+            Ok((width, height))
+        }
+        _ => Err(format!(
+            "expected a size such as '800x600', of at least {} pixels on each side, found '{}'",
+            MIN_WINDOW_SIZE, value
+        )),
+    }
+}
+
+fn parse_bool(value: &str) -> Result<bool, String> {
+    match value {
+        "true" | "yes" | "on" => Ok(true),
+        "false" | "no" | "off" => Ok(false),
+        _ => Err(format!("expected 'true' or 'false', found '{}'", value)),
+    }
+}
+
+impl Config {
+    /// Returns the path of the default configuration file.
+    ///
+    /// This is `$XDG_CONFIG_HOME/wordle/config.toml`, or
+    /// `$HOME/.config/wordle/config.toml` when `XDG_CONFIG_HOME` is not set.
+    pub fn default_path() -> Option<PathBuf> {
+        let base = match std::env::var_os("XDG_CONFIG_HOME") {
+            Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+            _ => PathBuf::from(std::env::var_os("HOME")?).join(".config"),
+        };
+
+        Some(base.join("wordle").join("config.toml"))
+    }
+
+    /// Returns the name of the default word list of the configured language.
+    pub fn default_words_name(&self) -> String {
+        match self.language.code {
+            "en" => "words.txt".into(),
+            code => format!("words.{}.txt", code),
+        }
+    }
+
+    /// Returns the layout of the on-screen keyboard.
+    pub fn layout(&self) -> &'static Layout {
+        self.layout.unwrap_or(self.language.layout)
+    }
+
+    /// Returns the scale of the window.
+    ///
+    /// Unless set explicitly, this is the scale requested for HiDPI screens by
+    /// `GDK_SCALE` or `QT_SCALE_FACTOR`, or 1.
+    pub fn scale(&self) -> f32 {
+        self.scale.unwrap_or_else(|| {
+            ["GDK_SCALE", "QT_SCALE_FACTOR"]
+                .into_iter()
+                .find_map(|var| parse_scale(&std::env::var(var).ok()?).ok())
+                .unwrap_or(1.0)
+        })
+    }
+
+    /// Sets the setting named `key` to `value`.
+    ///
+    /// Keys are the names of the long command-line flags, without the leading
+    /// dashes.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "words" => self.words = Some(PathBuf::from(value)),
+            "answers" => self.answers = Some(PathBuf::from(value)),
+            "assets" => self.assets = Some(PathBuf::from(value)),
+            "screenshot" => self.screenshot = Some(PathBuf::from(value)),
+            "lang" => self.language = parse_language(value)?,
+            "layout" if value == "auto" => self.layout = None,
+            "layout" => {
+                self.layout = Some(Layout::from_name(value).ok_or_else(|| {
+                    let names: Vec<&str> = LAYOUTS.iter().map(|layout| layout.name).collect();
+                    format!(
+                        "unknown layout '{}' (expected 'auto' or one of: {})",
+                        value,
+                        names.join(", ")
+                    )
+                })?)
+            }
+            "mode" => {
+                self.mode = match value {
+                    "random" => Mode::Random,
+                    "daily" => Mode::Daily,
+                    _ => {
+                        return Err(format!(
+                            "unknown mode '{}' (expected 'random' or 'daily')",
+                            value
+                        ))
+                    }
+                }
+            }
+            "length" => self.rules.word_size = parse_number(value, 1, MAX_WORD_SIZE)?,
+            "tries" => self.rules.max_tries = parse_number(value, 1, MAX_TRIES)?,
+            "hard" => self.rules.hard = parse_bool(value)?,
+            "theme" => {
+                self.theme = Theme::from_name(value).ok_or_else(|| {
+                    let names: Vec<&str> = THEMES.iter().map(|theme| theme.name).collect();
+                    format!(
+                        "unknown theme '{}' (expected one of: {})",
+                        value,
+                        names.join(", ")
+                    )
+                })?
+            }
+            "reduced-motion" => self.reduced_motion = parse_bool(value)?,
+            "show-help" => self.show_help = parse_bool(value)?,
+            "scale" if value == "auto" => self.scale = None,
+            "scale" => self.scale = Some(parse_scale(value)?),
+            "size" if value == "auto" => self.window_size = None,
+            "size" => self.window_size = Some(parse_window_size(value)?),
+            "seed" => {
+                self.seed = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("expected a 32-bit seed, found '{}'", value))?,
+                )
+            }
+            "ui" => {
+                self.ui = match value {
+                    "mlx" => Ui::Mlx,
+                    "terminal" => Ui::Terminal,
+                    "headless" => Ui::Headless,
+                    _ => {
+                        return Err(format!(
+                        "unknown user interface '{}' (expected 'mlx', 'terminal' or 'headless')",
+                        value
+                    ))
+                    }
+                }
+            }
+            _ => return Err(format!("unknown setting '{}'", key)),
+        }
+
+        Ok(())
+    }
+
+    /// Returns the value of the setting named `key`, as it would be written
+    /// in the configuration file.
+    ///
+    /// Only the settings that can be changed while playing are supported.
+    pub fn get(&self, key: &str) -> Option<String> {
+        Some(match key {
+            "mode" => match self.mode {
+                Mode::Random => "random".into(),
+                Mode::Daily => "daily".into(),
+            },
+            "hard" => self.rules.hard.to_string(),
+            "theme" => self.theme.name.into(),
+            "layout" => self.layout.map_or("auto", |layout| layout.name).into(),
+            "reduced-motion" => self.reduced_motion.to_string(),
+            "show-help" => self.show_help.to_string(),
+            _ => return None,
+        })
+    }
+
+    /// Writes the settings named `keys` to the configuration file.
+    ///
+    /// Lines setting those keys are replaced, and the other lines of the file
+    /// are kept as is. Nothing is written if there is no configuration file.
+    pub fn save(&self, keys: &[&str]) -> Result<(), ConfigError> {
+        let path = match &self.path {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+        let write_error = |err| ConfigError::Write(path.clone(), err);
+
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(ConfigError::Io(path.clone(), err)),
+        };
+
+        let mut saved = Vec::new();
+        let mut lines = Vec::new();
+        for line in content.lines() {
+            match parse_line(line) {
+                Ok(Some((key, _))) if keys.contains(&key) => {
+                    // A key set several times is only kept once.
+                    if !saved.contains(&key) {
+                        lines.push(format!("{} = {}", key, self.get(key).unwrap_or_default()));
+                        saved.push(key);
+                    }
+                }
+                _ => lines.push(line.to_string()),
+            }
+        }
+        for &key in keys.iter().filter(|key| !saved.contains(key)) {
+            lines.push(format!("{} = {}", key, self.get(key).unwrap_or_default()));
+        }
+
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(write_error)?;
+        }
+        std::fs::write(path, lines.join("\n") + "\n").map_err(write_error)
+    }
+
+    /// Reads the configuration file at `path` and applies its settings.
+    ///
+    /// The file is made of `key = value` lines. Values may be quoted, and
+    /// anything after a `#` is ignored.
+    pub fn load_file(&mut self, path: &Path) -> Result<(), ConfigError> {
+        let content =
+            std::fs::read_to_string(path).map_err(|err| ConfigError::Io(path.into(), err))?;
+
+        for (i, line) in content.lines().enumerate() {
+            let syntax_error = |message: String| ConfigError::Syntax {
+                path: path.into(),
+                line: i + 1,
+                message,
+            };
+
+            let (key, value) = match parse_line(line).map_err(syntax_error)? {
+                Some(entry) => entry,
+                None => continue,
+            };
+
+            self.set(key, &value).map_err(syntax_error)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses a single line of the configuration file.
+///
+/// Returns [`None`] for blank lines and comments.
+fn parse_line(line: &str) -> Result<Option<(&str, String)>, String> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return Ok(None);
+    }
+
+    let (key, rest) = line
+        .split_once('=')
+        .ok_or_else(|| format!("expected 'key = value', found '{}'", line))?;
+    let key = key.trim();
+    let rest = rest.trim();
+
+    if key.is_empty() {
+        return Err("missing key before '='".into());
+    }
+
+    let mut value = String::new();
+    if let Some(quoted) = rest.strip_prefix('"') {
+        let mut chars = quoted.chars();
+        loop {
+            match chars.next() {
+                Some('"') => break,
+                Some('\\') => match chars.next() {
+                    Some('n') => value.push('\n'),
+                    Some('t') => value.push('\t'),
+                    Some(c @ ('"' | '\\')) => value.push(c),
+                    _ => return Err("invalid escape sequence in string".into()),
+                },
+                Some(c) => value.push(c),
+                None => return Err("unterminated string".into()),
+            }
+        }
+
+        let trailing = chars.as_str().trim();
+        if !trailing.is_empty() && !trailing.starts_with('#') {
+            return Err(format!("unexpected '{}' after string", trailing));
+        }
+    } else {
+        let bare = rest.split('#').next().unwrap_or_default().trim();
+        if bare.is_empty() {
+            return Err(format!("missing value for '{}'", key));
+        }
+        value.push_str(bare);
+    }
+
+    Ok(Some((key, value)))
+}
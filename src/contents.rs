@@ -1,43 +1,188 @@
-use mlx::{Image, Mlx};
-
-use crate::cstr;
-
-pub struct Images {
-    pub black_letters: Image,
-    pub green_letters: Image,
-    pub grey_letters: Image,
-    pub yellow_letters: Image,
-    pub won_final_screen: Image,
-    pub lost_final_screen: Image,
-    pub winning_letters: Image,
-    pub black_letters_32: Image,
-    pub green_letters_32: Image,
-    pub grey_letters_32: Image,
-    pub yellow_letters_32: Image,
+use std::fmt;
+use std::path::PathBuf;
+
+use mlx::{Blend, Color, Rect};
+
+use crate::canvas::Canvas;
+use crate::decode::{self, DecodeError};
+use crate::frontend::Frontend;
+use crate::theme::{Theme, LIGHT};
+
+/// Where the images are loaded from.
+pub enum AssetSource {
+    /// A directory on disk.
+    Dir(PathBuf),
+    /// The images embedded in the executable.
+    #[cfg(feature = "embed")]
+    Embedded,
+}
+
+/// An error that might occur whilst loading the images.
+#[derive(Debug)]
+pub enum AssetError {
+    /// The file could not be read.
+    Io(PathBuf, std::io::Error),
+    /// The image is not embedded in the executable.
+    #[cfg(feature = "embed")]
+    NotEmbedded(String),
+    /// The image is invalid.
+    Decode { name: String, error: DecodeError },
+}
+
+impl fmt::Display for AssetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io(path, err) => write!(f, "failed to read '{}': {}", path.display(), err),
+            #[cfg(feature = "embed")]
+            Self::NotEmbedded(name) => write!(f, "'{}' is not embedded", name),
+            Self::Decode { name, error } => match error.line {
+                Some(line) => write!(f, "{}:{}: {}", name, line, error.problem),
+                None => write!(f, "{}: {}", name, error.problem),
+            },
+        }
+    }
 }
 
-impl Images {
-    /// ## Safety
-    ///
-    /// The created instance must be dropped after `mlx`.
-    pub unsafe fn load(mlx: &Mlx) -> Self {
-        unsafe fn load_image(mlx: &Mlx, name: &str) -> Image {
-            mlx.create_image_from_xpm_file(cstr(name))
-                .unwrap_or_else(|_| panic!("Failed to load '{}'.", name))
+pub struct Images<C> {
+    pub black_letters: C,
+    pub green_letters: C,
+    pub grey_letters: C,
+    pub yellow_letters: C,
+    pub won_final_screen: C,
+    pub lost_final_screen: C,
+    pub winning_letters: C,
+    pub black_letters_32: C,
+    pub green_letters_32: C,
+    pub grey_letters_32: C,
+    pub yellow_letters_32: C,
+}
+
+/// Copies `image`, replacing the colors `from` by `to`.
+///
+/// Both are pairs of a background and an ink color. Other colors of the image
+/// are taken as mixes of the two, such as the edges of the letters.
+fn recolored<F: Frontend>(
+    frontend: &F,
+    image: &F::Canvas,
+    from: (Color, Color),
+    to: (Color, Color),
+) -> F::Canvas {
+    let mut copy = frontend.create_canvas(image.width(), image.height());
+    let area = Rect::new(0, 0, image.width(), image.height());
+    copy.blit(image, area, 0, 0, Blend::Replace);
+
+    if from == to {
+        return copy;
+    }
+
+    let (background, ink) = from;
+    let channels = |c: Color| [c.r as f32, c.g as f32, c.b as f32];
+    let origin = channels(background);
+    let axis: Vec<f32> = (0..3).map(|i| channels(ink)[i] - origin[i]).collect();
+    let length = axis.iter().map(|a| a * a).sum::<f32>();
+
+    for y in 0..copy.height() {
+        for x in 0..copy.width() {
+            let pixel = copy.get_pixel(x, y).unwrap();
+            let dot: f32 = (0..3)
+                .map(|i| (channels(pixel)[i] - origin[i]) * axis[i])
+                .sum();
+
+            let color = to.0.mix(to.1, (dot / length).clamp(0.0, 1.0));
+            copy.set_pixel(
+                x,
+                y,
+                Color {
+                    a: pixel.a,
+                    ..color
+                },
+            );
         }
+    }
+
+    copy
+}
+
+impl<C: Canvas> Images<C> {
+    /// Decodes the images found in `source`, and uploads them to
+    /// `frontend`.
+    pub fn load<F: Frontend<Canvas = C>>(
+        frontend: &F,
+        source: &AssetSource,
+    ) -> Result<Self, AssetError> {
+        let upload = |data: &[u8], name: String| match decode::image(data) {
+            Ok(image) => Ok(frontend.load_image(image)),
+            Err(error) => Err(AssetError::Decode { name, error }),
+        };
+        let load_image = |name: &str| -> Result<C, AssetError> {
+            match source {
+                AssetSource::Dir(dir) => {
+                    let path = dir.join(name);
+                    match std::fs::read(&path) {
+                        Ok(data) => upload(&data, path.display().to_string()),
+                        Err(err) => Err(AssetError::Io(path, err)),
+                    }
+                }
+                #[cfg(feature = "embed")]
+                AssetSource::Embedded => match crate::embedded::asset(name) {
+                    Some(data) => upload(data, format!("embedded {}", name)),
+                    None => Err(AssetError::NotEmbedded(name.into())),
+                },
+            }
+        };
+
+        Ok(Self {
+            black_letters: load_image("alphabet_neutral.xpm")?,
+            green_letters: load_image("alphabet_correct.xpm")?,
+            yellow_letters: load_image("alphabet_misplaced.xpm")?,
+            grey_letters: load_image("alphabet_incorrect.xpm")?,
+            won_final_screen: load_image("you_won.xpm")?,
+            lost_final_screen: load_image("you_lost.xpm")?,
+            winning_letters: load_image("alphabet_correct.xpm")?,
+            black_letters_32: load_image("alphabet_neutral_keyboard.xpm")?,
+            green_letters_32: load_image("alphabet_correct_keyboard.xpm")?,
+            yellow_letters_32: load_image("alphabet_misplaced_keyboard.xpm")?,
+            grey_letters_32: load_image("alphabet_incorrect_keyboard.xpm")?,
+        })
+    }
+
+    /// Returns a copy of the images with the colors of `theme`.
+    pub fn themed<F: Frontend<Canvas = C>>(&self, frontend: &F, theme: &Theme) -> Self {
+        let white = Color::rgb(0xffffff);
+        let neutral = (LIGHT.tile, LIGHT.ink);
+        let neutral_key = (LIGHT.tile, LIGHT.key_ink);
+        let screen = (LIGHT.background, Color::rgb(0x010101));
+        let to_neutral = (theme.tile, theme.ink);
+        let to_neutral_key = (theme.tile, theme.key_ink);
+        let to_screen = (theme.background, theme.ink);
+        let scored = |from: Color, to: Color| ((from, white), (to, theme.scored_ink));
+
+        let (correct, to_correct) = scored(LIGHT.correct, theme.correct);
+        let (misplaced, to_misplaced) = scored(LIGHT.misplaced, theme.misplaced);
+        let (incorrect, to_incorrect) = scored(LIGHT.incorrect, theme.incorrect);
 
         Self {
-            black_letters: load_image(mlx, "assets/alphabet_neutral.xpm\0"),
-            green_letters: load_image(mlx, "assets/alphabet_correct.xpm\0"),
-            yellow_letters: load_image(mlx, "assets/alphabet_misplaced.xpm\0"),
-            grey_letters: load_image(mlx, "assets/alphabet_incorrect.xpm\0"),
-            won_final_screen: load_image(mlx, "assets/you_won.xpm\0"),
-            lost_final_screen: load_image(mlx, "assets/you_lost.xpm\0"),
-            winning_letters: load_image(mlx, "assets/alphabet_correct.xpm\0"),
-            black_letters_32: load_image(mlx, "assets/alphabet_neutral_keyboard.xpm\0"),
-            green_letters_32: load_image(mlx, "assets/alphabet_correct_keyboard.xpm\0"),
-            yellow_letters_32: load_image(mlx, "assets/alphabet_misplaced_keyboard.xpm\0"),
-            grey_letters_32: load_image(mlx, "assets/alphabet_incorrect_keyboard.xpm\0"),
+            black_letters: recolored(frontend, &self.black_letters, neutral, to_neutral),
+            green_letters: recolored(frontend, &self.green_letters, correct, to_correct),
+            yellow_letters: recolored(frontend, &self.yellow_letters, misplaced, to_misplaced),
+            grey_letters: recolored(frontend, &self.grey_letters, incorrect, to_incorrect),
+            won_final_screen: recolored(frontend, &self.won_final_screen, screen, to_screen),
+            lost_final_screen: recolored(frontend, &self.lost_final_screen, screen, to_screen),
+            winning_letters: recolored(frontend, &self.winning_letters, correct, to_correct),
+            black_letters_32: recolored(
+                frontend,
+                &self.black_letters_32,
+                neutral_key,
+                to_neutral_key,
+            ),
+            green_letters_32: recolored(frontend, &self.green_letters_32, correct, to_correct),
+            yellow_letters_32: recolored(
+                frontend,
+                &self.yellow_letters_32,
+                misplaced,
+                to_misplaced,
+            ),
+            grey_letters_32: recolored(frontend, &self.grey_letters_32, incorrect, to_incorrect),
         }
     }
 }
@@ -0,0 +1,72 @@
+//! Decodes the images of the assets without the MiniLibX, so that errors can
+//! be reported in detail, and so that frontends without a display can use
+//! them.
+
+use std::fmt;
+
+use crate::canvas::Framebuffer;
+
+mod png;
+mod xpm;
+
+/// The largest number of pixels an image may have, so that a malformed file
+/// cannot make the game run out of memory.
+const MAX_PIXELS: usize = 1 << 24;
+
+/// Why an image could not be decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeError {
+    /// The line of the file the problem is on, starting at 1, for the formats
+    /// made of lines.
+    pub line: Option<usize>,
+    /// What the problem is.
+    pub problem: String,
+}
+
+impl DecodeError {
+    fn new(problem: impl Into<String>) -> Self {
+        Self {
+            line: None,
+            problem: problem.into(),
+        }
+    }
+
+    fn at(line: usize, problem: impl Into<String>) -> Self {
+        Self {
+            line: Some(line),
+            problem: problem.into(),
+        }
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.problem),
+            None => f.write_str(&self.problem),
+        }
+    }
+}
+
+/// Checks that an image of `width` by `height` pixels is small enough to be
+/// decoded, and returns its size.
+fn check_size(width: usize, height: usize) -> Result<(u32, u32), String> {
+    match width.checked_mul(height) {
+        Some(pixels) if pixels <= MAX_PIXELS => Ok((width as u32, height as u32)),
+        _ => Err(format!(
+            "the image is too large ({}x{} pixels, {} at most)",
+            width, height, MAX_PIXELS
+        )),
+    }
+}
+
+/// Decodes the PNG or XPM image `data`.
+///
+/// The format is told by the first bytes of the file, whatever its name.
+pub fn image(data: &[u8]) -> Result<Framebuffer, DecodeError> {
+    if data.starts_with(png::SIGNATURE) {
+        png::decode(data)
+    } else {
+        xpm::decode(data)
+    }
+}
@@ -0,0 +1,414 @@
+use mlx::Color;
+
+use super::DecodeError;
+use crate::canvas::{Canvas, Framebuffer};
+use crate::zlib;
+
+/// The first bytes of every PNG file.
+pub const SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+/// How the pixels are stored.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ColorType {
+    Gray,
+    Rgb,
+    Indexed,
+    GrayAlpha,
+    Rgba,
+}
+
+impl ColorType {
+    /// Returns the number of samples of each pixel.
+    fn channels(self) -> usize {
+        match self {
+            Self::Gray | Self::Indexed => 1,
+            Self::GrayAlpha => 2,
+            Self::Rgb => 3,
+            Self::Rgba => 4,
+        }
+    }
+}
+
+/// The content of the `IHDR` chunk.
+struct Header {
+    width: u32,
+    height: u32,
+    bit_depth: u8,
+    color_type: ColorType,
+}
+
+impl Header {
+    fn parse(chunk: &[u8]) -> Result<Self, DecodeError> {
+        let error = |problem: String| DecodeError::new(format!("IHDR chunk: {}", problem));
+
+        let &[w0, w1, w2, w3, h0, h1, h2, h3, bit_depth, color_type, compression, filter, interlace] =
+            chunk
+        else {
+            return Err(error(format!("expected 13 bytes, found {}", chunk.len())));
+        };
+        let width = u32::from_be_bytes([w0, w1, w2, w3]);
+        let height = u32::from_be_bytes([h0, h1, h2, h3]);
+        if width == 0 || height == 0 {
+            return Err(error(format!("invalid size {}x{}", width, height)));
+        }
+        super::check_size(width as usize, height as usize).map_err(error)?;
+
+        let (color_type, depths): (_, &[u8]) = match color_type {
+            0 => (ColorType::Gray, &[1, 2, 4, 8, 16]),
+            2 => (ColorType::Rgb, &[8, 16]),
+            3 => (ColorType::Indexed, &[1, 2, 4, 8]),
+            4 => (ColorType::GrayAlpha, &[8, 16]),
+            6 => (ColorType::Rgba, &[8, 16]),
+            _ => return Err(error(format!("invalid color type {}", color_type))),
+        };
+        if !depths.contains(&bit_depth) {
+            return Err(error(format!(
+                "invalid bit depth {} for the color type",
+                bit_depth
+            )));
+        }
+        if compression != 0 || filter != 0 {
+            return Err(error("unknown compression or filter method".into()));
+        }
+        if interlace != 0 {
+            return Err(error("interlaced images are not supported".into()));
+        }
+
+        Ok(Self {
+            width,
+            height,
+            bit_depth,
+            color_type,
+        })
+    }
+
+    /// Returns the number of bits of each pixel.
+    fn bits_per_pixel(&self) -> usize {
+        self.color_type.channels() * self.bit_depth as usize
+    }
+}
+
+/// Returns the predictor of the `Paeth` filter.
+fn paeth(left: u8, above: u8, upper_left: u8) -> u8 {
+    let p = left as i16 + above as i16 - upper_left as i16;
+    let (pa, pb, pc) = (
+        (p - left as i16).abs(),
+        (p - above as i16).abs(),
+        (p - upper_left as i16).abs(),
+    );
+    if pa <= pb && pa <= pc {
+        left
+    } else if pb <= pc {
+        above
+    } else {
+        upper_left
+    }
+}
+
+/// Reverses the filters of the rows of `data`, each of `stride` bytes after
+/// the byte giving its filter. Returns the rows without that byte.
+fn unfilter(data: &[u8], header: &Header, stride: usize) -> Result<Vec<u8>, DecodeError> {
+    let height = header.height as usize;
+    if data.len() != (stride + 1) * height {
+        return Err(DecodeError::new(format!(
+            "IDAT chunk: expected {} bytes of pixels, found {}",
+            (stride + 1) * height,
+            data.len()
+        )));
+    }
+
+    // The filters combine a byte with the same byte of the previous pixel.
+    let step = header.bits_per_pixel().div_ceil(8);
+    let mut rows = vec![0u8; stride * height];
+    for (y, row) in data.chunks_exact(stride + 1).enumerate() {
+        let (filter, row) = (row[0], &row[1..]);
+        let (done, rest) = rows.split_at_mut(y * stride);
+        let previous = (y > 0).then(|| &done[(y - 1) * stride..]);
+        let current = &mut rest[..stride];
+
+        for i in 0..stride {
+            let left = if i >= step { current[i - step] } else { 0 };
+            let above = previous.map_or(0, |previous| previous[i]);
+            let upper_left = match previous {
+                Some(previous) if i >= step => previous[i - step],
+                _ => 0,
+            };
+            let predictor = match filter {
+                0 => 0,
+                1 => left,
+                2 => above,
+                3 => ((left as u16 + above as u16) / 2) as u8,
+                4 => paeth(left, above, upper_left),
+                _ => {
+                    return Err(DecodeError::new(format!(
+                        "IDAT chunk: invalid filter {} on row {}",
+                        filter,
+                        y + 1
+                    )))
+                }
+            };
+            current[i] = row[i].wrapping_add(predictor);
+        }
+    }
+
+    Ok(rows)
+}
+
+/// Decodes the PNG image `data`.
+///
+/// Interlaced images are not supported.
+pub fn decode(data: &[u8]) -> Result<Framebuffer, DecodeError> {
+    let mut rest = data
+        .strip_prefix(SIGNATURE)
+        .ok_or_else(|| DecodeError::new("not a PNG image"))?;
+
+    let mut header = None;
+    let mut palette = Vec::new();
+    let mut transparency: &[u8] = &[];
+    let mut compressed = Vec::new();
+    loop {
+        if rest.len() < 12 {
+            return Err(DecodeError::new("the file is truncated"));
+        }
+        let len = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+        let name = String::from_utf8_lossy(&rest[4..8]).into_owned();
+        if rest.len() - 12 < len {
+            return Err(DecodeError::new(format!("{} chunk: truncated", name)));
+        }
+        let chunk = &rest[8..8 + len];
+        let crc = u32::from_be_bytes(rest[8 + len..12 + len].try_into().unwrap());
+        if zlib::crc32(&rest[4..8 + len]) != crc {
+            return Err(DecodeError::new(format!(
+                "{} chunk: the checksum does not match",
+                name
+            )));
+        }
+        rest = &rest[12 + len..];
+
+        match name.as_str() {
+            "IHDR" => header = Some(Header::parse(chunk)?),
+            "PLTE" => {
+                if !len.is_multiple_of(3) {
+                    return Err(DecodeError::new(format!(
+                        "PLTE chunk: {} bytes is not a number of colors",
+                        len
+                    )));
+                }
+                palette = chunk
+                    .chunks_exact(3)
+                    .map(|c| Color::rgba(c[0], c[1], c[2], 255))
+                    .collect();
+            }
+            "tRNS" => transparency = chunk,
+            "IDAT" => compressed.extend_from_slice(chunk),
+            "IEND" => break,
+            // Chunks whose name starts with a lowercase letter may be
+            // ignored; the others are needed to show the image.
+            _ if name.starts_with(|c: char| c.is_ascii_uppercase()) => {
+                return Err(DecodeError::new(format!(
+                    "{} chunk: unsupported critical chunk",
+                    name
+                )))
+            }
+            _ => (),
+        }
+    }
+
+    let header = header.ok_or_else(|| DecodeError::new("missing the IHDR chunk"))?;
+    if header.color_type == ColorType::Indexed && palette.is_empty() {
+        return Err(DecodeError::new("missing the PLTE chunk"));
+    }
+    // Each row starts with the byte giving its filter.
+    let stride = (header.width as usize * header.bits_per_pixel()).div_ceil(8);
+    let data = zlib::decompress(&compressed, (stride + 1) * header.height as usize)
+        .map_err(|problem| DecodeError::new(format!("IDAT chunk: {}", problem)))?;
+    let rows = unfilter(&data, &header, stride)?;
+
+    // Reads the sample `i` of a row, as stored and scaled to 8 bits.
+    let depth = header.bit_depth as usize;
+    let max = (1u32 << depth) - 1;
+    let sample = |row: &[u8], i: usize| -> (u16, u8) {
+        let value = match depth {
+            16 => u16::from_be_bytes([row[i * 2], row[i * 2 + 1]]),
+            8 => row[i] as u16,
+            _ => {
+                let shift = 8 - depth - (i * depth) % 8;
+                ((row[i * depth / 8] >> shift) as u16) & max as u16
+            }
+        };
+        (value, (value as u32 * 255 / max) as u8)
+    };
+    // The color made transparent by the `tRNS` chunk, for the images without
+    // an alpha channel.
+    let key: Vec<u16> = transparency
+        .chunks_exact(2)
+        .map(|c| u16::from_be_bytes([c[0], c[1]]))
+        .collect();
+
+    let mut image = Framebuffer::new(header.width, header.height);
+    let channels = header.color_type.channels();
+    for (y, row) in rows.chunks_exact(stride).enumerate() {
+        for x in 0..header.width as usize {
+            let mut samples = [(0, 0); 4];
+            for (c, s) in samples.iter_mut().enumerate().take(channels) {
+                *s = sample(row, x * channels + c);
+            }
+            let color = match header.color_type {
+                ColorType::Gray => {
+                    let (value, v) = samples[0];
+                    let a = if key == [value] { 0 } else { 255 };
+                    Color::rgba(v, v, v, a)
+                }
+                ColorType::Rgb => {
+                    let values = samples[..3].iter().map(|s| s.0);
+                    let a = if key.iter().copied().eq(values) {
+                        0
+                    } else {
+                        255
+                    };
+                    Color::rgba(samples[0].1, samples[1].1, samples[2].1, a)
+                }
+                ColorType::Indexed => {
+                    let index = samples[0].0 as usize;
+                    let color = *palette.get(index).ok_or_else(|| {
+                        DecodeError::new(format!(
+                            "IDAT chunk: color {} is not in the palette of {} colors",
+                            index,
+                            palette.len()
+                        ))
+                    })?;
+                    let a = transparency.get(index).copied().unwrap_or(255);
+                    Color { a, ..color }
+                }
+                ColorType::GrayAlpha => {
+                    let v = samples[0].1;
+                    Color::rgba(v, v, v, samples[1].1)
+                }
+                ColorType::Rgba => {
+                    Color::rgba(samples[0].1, samples[1].1, samples[2].1, samples[3].1)
+                }
+            };
+            image.set_pixel(x as u32, y as u32, color);
+        }
+    }
+
+    Ok(image)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::canvas::Canvas;
+
+    /// Encodes a small image with several colors.
+    fn image() -> (Framebuffer, Vec<u8>) {
+        let mut image = Framebuffer::new(5, 3);
+        for y in 0..3 {
+            for x in 0..5 {
+                image.set_pixel(x, y, Color::rgb(x * 0x330000 + y * 0x66 + 0x0c00));
+            }
+        }
+        let data = crate::export::png(&image);
+        (image, data)
+    }
+
+    /// Builds a PNG file made of `chunks`.
+    fn file(chunks: &[(&[u8], &[u8])]) -> Vec<u8> {
+        let mut data = SIGNATURE.to_vec();
+        for (name, content) in chunks {
+            let start = data.len() + 4;
+            data.extend((content.len() as u32).to_be_bytes());
+            data.extend(*name);
+            data.extend(*content);
+            let crc = zlib::crc32(&data[start..]);
+            data.extend(crc.to_be_bytes());
+        }
+        data
+    }
+
+    /// Returns the content of an `IHDR` chunk.
+    fn header(width: u32, height: u32, bit_depth: u8, color_type: u8) -> Vec<u8> {
+        let mut header = width.to_be_bytes().to_vec();
+        header.extend(height.to_be_bytes());
+        header.extend([bit_depth, color_type, 0, 0, 0]);
+        header
+    }
+
+    #[test]
+    fn valid() {
+        let (image, data) = image();
+        assert_eq!(decode(&data), Ok(image));
+    }
+
+    #[test]
+    fn palette() {
+        // Two pixels of 1 bit, the second one transparent.
+        let rows = zlib::compress(&[0, 0b0100_0000]);
+        let data = file(&[
+            (b"IHDR", &header(2, 1, 1, 3)),
+            (b"PLTE", &[0xff, 0, 0, 0, 0xff, 0]),
+            (b"tRNS", &[0xff, 0]),
+            (b"IDAT", &rows),
+            (b"IEND", &[]),
+        ]);
+        let image = decode(&data).unwrap();
+        assert_eq!(image.get_pixel(0, 0), Some(Color::rgb(0xff0000)));
+        assert_eq!(image.get_pixel(1, 0), Some(Color::rgba(0, 0xff, 0, 0)));
+    }
+
+    #[test]
+    fn truncated() {
+        let (_, data) = image();
+        for len in [0, SIGNATURE.len(), 20, data.len() - 13, data.len() - 1] {
+            assert!(decode(&data[..len]).is_err(), "{} bytes", len);
+        }
+    }
+
+    #[test]
+    fn corrupt() {
+        let (_, mut data) = image();
+        // A byte of the content of the IDAT chunk.
+        data[45] ^= 0xff;
+        assert_eq!(
+            decode(&data),
+            Err(DecodeError::new("IDAT chunk: the checksum does not match"))
+        );
+
+        let data = file(&[(b"IHDR", &header(2, 1, 3, 2)), (b"IEND", &[])]);
+        assert_eq!(
+            decode(&data),
+            Err(DecodeError::new(
+                "IHDR chunk: invalid bit depth 3 for the color type"
+            ))
+        );
+
+        let data = file(&[(b"IHDR", &header(2, 1, 8, 2)), (b"IEND", &[])]);
+        assert!(decode(&data).is_err());
+    }
+
+    #[test]
+    fn too_large() {
+        let data = file(&[(b"IHDR", &header(u32::MAX, u32::MAX, 8, 6)), (b"IEND", &[])]);
+        let err = decode(&data).unwrap_err();
+        assert!(err
+            .problem
+            .starts_with("IHDR chunk: the image is too large"));
+    }
+
+    #[test]
+    fn too_long() {
+        // A single pixel, followed by a megabyte of zeros.
+        let rows = zlib::compress(&vec![0; 1 << 20]);
+        let data = file(&[
+            (b"IHDR", &header(1, 1, 8, 0)),
+            (b"IDAT", &rows),
+            (b"IEND", &[]),
+        ]);
+        assert_eq!(
+            decode(&data),
+            Err(DecodeError::new(
+                "IDAT chunk: the decompressed data is longer than expected"
+            ))
+        );
+    }
+}
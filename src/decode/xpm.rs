@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+
+use mlx::Color;
+
+use super::DecodeError;
+use crate::canvas::{Canvas, Framebuffer};
+
+/// Extracts the strings of an XPM file, the values, the colors and the pixels
+/// in order, along with the number of the line each one is on.
+///
+/// Comments are skipped, along with the strings they contain. Quotes that are
+/// not closed on the same line are ignored.
+fn strings(data: &[u8]) -> impl Iterator<Item = (usize, &[u8])> {
+    let (mut i, mut line) = (0, 1);
+    std::iter::from_fn(move || {
+        while i < data.len() {
+            let rest = &data[i..];
+            if rest.starts_with(b"/*") {
+                // A comment that is not closed lasts until the end of the file.
+                let len = rest
+                    .windows(2)
+                    .skip(2)
+                    .position(|end| end == b"*/")
+                    .map_or(rest.len(), |end| end + 4);
+                line += rest[..len].iter().filter(|&&c| c == b'\n').count();
+                i += len;
+                continue;
+            }
+
+            i += 1;
+            match rest[0] {
+                b'\n' => line += 1,
+                b'"' => {
+                    let len = rest[1..].iter().position(|&c| c == b'"' || c == b'\n');
+                    if let Some(len) = len.filter(|&len| rest[1 + len] == b'"') {
+                        i += len + 1;
+                        return Some((line, &rest[1..1 + len]));
+                    }
+                }
+                _ => (),
+            }
+        }
+        None
+    })
+}
+
+/// Parses a color of an XPM file, such as `#639459` or `None`.
+fn parse_color(value: &str) -> Option<Color> {
+    if value.eq_ignore_ascii_case("none") {
+        return Some(Color::TRANSPARENT);
+    }
+
+    let hex = value.strip_prefix('#')?;
+    let value = u32::from_str_radix(hex, 16).ok()?;
+    match hex.len() {
+        6 => Some(Color::rgb(value)),
+        // `#RGB` stands for `#RRGGBB`.
+        3 => Some(Color::rgb(
+            ((value & 0xf00) * 0x1100) | ((value & 0xf0) * 0x110) | ((value & 0xf) * 0x11),
+        )),
+        _ => None,
+    }
+}
+
+/// Reads `string`, found on `line`, as text.
+fn text(line: usize, string: &[u8]) -> Result<&str, DecodeError> {
+    std::str::from_utf8(string).map_err(|_| DecodeError::at(line, "invalid UTF-8"))
+}
+
+/// Decodes the XPM image `data`.
+///
+/// Only the color visual (`c`) is used, which is the one the MiniLibX reads.
+pub fn decode(data: &[u8]) -> Result<Framebuffer, DecodeError> {
+    // Missing strings are reported on the last line of the file.
+    let last_line = data.split(|&c| c == b'\n').count();
+    let end = |expected: String| {
+        DecodeError::at(
+            last_line,
+            format!("expected {}, found the end of the file", expected),
+        )
+    };
+
+    let mut strings = strings(data);
+
+    let (line, values) = strings
+        .next()
+        .ok_or_else(|| DecodeError::new("not an XPM image: no string found"))?;
+    let values: Vec<usize> = text(line, values)?
+        .split_whitespace()
+        .take(4)
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| DecodeError::at(line, format!("invalid value '{}'", value)))
+        })
+        .collect::<Result<_, _>>()?;
+    let [width, height, colors, chars] = <[usize; 4]>::try_from(values).map_err(|values| {
+        DecodeError::at(
+            line,
+            format!(
+                "expected the width, height, number of colors and characters per pixel, \
+                 found {} value(s)",
+                values.len()
+            ),
+        )
+    })?;
+    if chars == 0 {
+        return Err(DecodeError::at(line, "there are 0 characters per pixel"));
+    }
+    let (image_width, image_height) =
+        super::check_size(width, height).map_err(|problem| DecodeError::at(line, problem))?;
+    let row_len = width
+        .checked_mul(chars)
+        .ok_or_else(|| DecodeError::at(line, "the rows of pixels are too long"))?;
+
+    // The number of colors is not trusted until they are read.
+    let mut palette = HashMap::new();
+    for _ in 0..colors {
+        let (line, string) = strings
+            .next()
+            .ok_or_else(|| end(format!("{} colors", colors)))?;
+        let key = string
+            .get(..chars)
+            .ok_or_else(|| DecodeError::at(line, format!("expected {} characters", chars)))?;
+        let mut words = text(line, &string[chars..])?.split_whitespace();
+        words
+            .find(|&word| word == "c")
+            .ok_or_else(|| DecodeError::at(line, "the color has no 'c' visual"))?;
+        let value = words
+            .next()
+            .ok_or_else(|| DecodeError::at(line, "missing the value of the 'c' visual"))?;
+        let color = parse_color(value)
+            .ok_or_else(|| DecodeError::at(line, format!("invalid color '{}'", value)))?;
+        if palette.insert(key, color).is_some() {
+            return Err(DecodeError::at(
+                line,
+                format!("'{}' is defined twice", String::from_utf8_lossy(key)),
+            ));
+        }
+    }
+
+    let mut image = Framebuffer::new(image_width, image_height);
+    for y in 0..height {
+        let (line, string) = strings
+            .next()
+            .ok_or_else(|| end(format!("{} rows of pixels", height)))?;
+        if string.len() != row_len {
+            return Err(DecodeError::at(
+                line,
+                format!("expected {} characters, found {}", row_len, string.len()),
+            ));
+        }
+        for (x, key) in string.chunks_exact(chars).enumerate() {
+            let color = palette.get(key).ok_or_else(|| {
+                DecodeError::at(
+                    line,
+                    format!(
+                        "unknown color '{}' in column {}",
+                        String::from_utf8_lossy(key),
+                        x + 1
+                    ),
+                )
+            })?;
+            image.set_pixel(x as u32, y as u32, *color);
+        }
+    }
+
+    Ok(image)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IMAGE: &str = r#"/* XPM */
+static char *image[] = {
+/* columns rows colors chars-per-pixel */
+"3 2 3 1 ",
+"  c None",
+". c #639459",
+"+ c #FFF",
+/* "not a row" */
+"+. ",
+/*
+"+++",
+*/
+" .+"
+};
+"#;
+
+    #[test]
+    fn valid() {
+        let image = decode(IMAGE.as_bytes()).unwrap();
+        assert_eq!((image.width(), image.height()), (3, 2));
+
+        let green = Color::rgb(0x639459);
+        let pixels = [
+            [Color::WHITE, green, Color::TRANSPARENT],
+            [Color::TRANSPARENT, green, Color::WHITE],
+        ];
+        for (y, row) in pixels.iter().enumerate() {
+            for (x, &color) in row.iter().enumerate() {
+                assert_eq!(image.get_pixel(x as u32, y as u32), Some(color));
+            }
+        }
+    }
+
+    #[test]
+    fn truncated() {
+        let data = &IMAGE.as_bytes()[..IMAGE.find("/*\n").unwrap()];
+        let err = decode(data).unwrap_err();
+        assert_eq!(err.line, Some(10));
+        assert_eq!(
+            err.problem,
+            "expected 2 rows of pixels, found the end of the file"
+        );
+    }
+
+    #[test]
+    fn corrupt() {
+        let error =
+            |from: &str, to: &str| decode(IMAGE.replacen(from, to, 1).as_bytes()).unwrap_err();
+
+        assert_eq!(
+            error("#FFF", "#FFFF"),
+            DecodeError::at(7, "invalid color '#FFFF'")
+        );
+        assert_eq!(
+            error("+. ", "+x "),
+            DecodeError::at(9, "unknown color 'x' in column 2")
+        );
+        assert_eq!(
+            error("+. ", "+."),
+            DecodeError::at(9, "expected 3 characters, found 2")
+        );
+        assert_eq!(
+            error(". c", "+ c"),
+            DecodeError::at(7, "'+' is defined twice")
+        );
+        let err = error("3 2 3 1", "3 2 3");
+        assert_eq!(err.line, Some(4));
+        assert!(err.problem.ends_with("found 3 value(s)"));
+        assert!(decode(b"no string").is_err());
+    }
+
+    #[test]
+    fn too_large() {
+        for values in ["100000 100000 3 1", "18446744073709551615 2 3 1"] {
+            let err = decode(IMAGE.replacen("3 2 3 1", values, 1).as_bytes()).unwrap_err();
+            assert_eq!(err.line, Some(4));
+            assert!(err.problem.starts_with("the image is too large"));
+        }
+
+        let err = decode(
+            IMAGE
+                .replacen("3 2 3 1", "3 2 3 18446744073709551615", 1)
+                .as_bytes(),
+        )
+        .unwrap_err();
+        assert_eq!(err, DecodeError::at(4, "the rows of pixels are too long"));
+    }
+}
@@ -0,0 +1,244 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::game::Word;
+use crate::language::Language;
+
+/// Why a line of a word list was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    /// The line is not valid UTF-8.
+    Encoding,
+    /// The word does not have the expected number of letters.
+    Length { expected: usize, found: usize },
+    /// The word contains a character that is not a letter of the language.
+    Character(char),
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Encoding => f.write_str("invalid UTF-8"),
+            Self::Length { expected, found } => {
+                write!(f, "expected {} letters, found {}", expected, found)
+            }
+            Self::Character(c) => write!(f, "invalid character {:?}", c),
+        }
+    }
+}
+
+/// The number of invalid lines listed in an error message. The others are
+/// only counted.
+const MAX_LISTED_LINES: usize = 10;
+
+/// A line of a word list that was rejected.
+#[derive(Debug, Clone)]
+pub struct InvalidLine {
+    /// The line number, starting at 1.
+    pub line: usize,
+    /// The content of the line.
+    pub content: String,
+    /// Why the line was rejected.
+    pub problem: Problem,
+}
+
+/// An error that might occur whilst loading a word list.
+#[derive(Debug)]
+pub enum DictError {
+    /// The file could not be read.
+    Io(PathBuf, std::io::Error),
+    /// Some lines of the list are invalid.
+    InvalidLines {
+        name: String,
+        lines: Vec<InvalidLine>,
+    },
+    /// The list contains no word of the expected length.
+    Empty { name: String, word_size: usize },
+}
+
+impl fmt::Display for DictError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io(path, err) => write!(f, "failed to read '{}': {}", path.display(), err),
+            Self::InvalidLines { name, lines } => {
+                write!(f, "'{}' contains {} invalid line(s):", name, lines.len())?;
+                for line in lines.iter().take(MAX_LISTED_LINES) {
+                    write!(
+                        f,
+                        "\n  line {}: '{}': {}",
+                        line.line,
+                        line.content.escape_debug(),
+                        line.problem
+                    )?;
+                }
+                if lines.len() > MAX_LISTED_LINES {
+                    write!(f, "\n  and {} more", lines.len() - MAX_LISTED_LINES)?;
+                }
+                Ok(())
+            }
+            Self::Empty { name, word_size } => {
+                write!(f, "'{}' contains no word of {} letters", name, word_size)
+            }
+        }
+    }
+}
+
+/// Counts the entries of a word list that were not turned into words.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LoadReport {
+    /// The number of words that were loaded.
+    pub words: usize,
+    /// The number of blank lines.
+    pub blank_lines: usize,
+    /// The number of lines that only contain a comment.
+    pub comments: usize,
+    /// The number of words that were already in the list.
+    pub duplicates: usize,
+    /// The number of words that do not have the expected number of letters.
+    pub other_lengths: usize,
+}
+
+impl LoadReport {
+    /// Returns whether any line of the list was not turned into a word.
+    pub fn skipped_any(&self) -> bool {
+        self.blank_lines + self.comments + self.duplicates + self.other_lengths != 0
+    }
+}
+
+impl fmt::Display for LoadReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} words ({} duplicates, {} words of another length, {} blank lines and {} \
+             comments skipped)",
+            self.words, self.duplicates, self.other_lengths, self.blank_lines, self.comments
+        )
+    }
+}
+
+/// A loaded word list.
+pub struct Dictionary {
+    /// The words of the list, sorted and without duplicates.
+    pub words: Vec<Word>,
+    /// What was skipped whilst loading the list.
+    pub report: LoadReport,
+}
+
+/// Parses a single word, normalizing its case, accents and ligatures.
+pub fn parse_word(word: &str, word_size: usize, language: &Language) -> Result<Word, Problem> {
+    let found = language.expand(word).count();
+    if found != word_size {
+        return Err(Problem::Length {
+            expected: word_size,
+            found,
+        });
+    }
+
+    language
+        .expand(word)
+        .map(|c| language.letter(c).ok_or(Problem::Character(c)))
+        .collect()
+}
+
+/// The content of a line of a word list.
+pub enum Line<'a> {
+    /// The line is empty.
+    Blank,
+    /// The line only contains a comment.
+    Comment,
+    /// The line contains a word, not yet validated.
+    Word(&'a str),
+    /// The line is not valid UTF-8.
+    Invalid(&'a [u8]),
+}
+
+/// Splits a word list into lines, numbered from 1.
+///
+/// Line endings may be `\n` or `\r\n`. Anything following a `#` is a comment.
+pub fn lines(data: &[u8]) -> impl Iterator<Item = (usize, Line<'_>)> {
+    // The final newline does not start a new line.
+    let data = data.strip_suffix(b"\n").unwrap_or(data);
+
+    data.split(|&c| c == b'\n').enumerate().map(|(i, line)| {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+
+        let text = match std::str::from_utf8(line) {
+            Ok(text) => text,
+            Err(_) => return (i + 1, Line::Invalid(line)),
+        };
+
+        let line = match text.split_once('#') {
+            Some((word, _)) if word.trim().is_empty() => Line::Comment,
+            Some((word, _)) => Line::Word(word.trim()),
+            None if text.trim().is_empty() => Line::Blank,
+            None => Line::Word(text.trim()),
+        };
+
+        (i + 1, line)
+    })
+}
+
+/// Parses a list of words, one per line.
+///
+/// Blank lines, comments and words that do not have `word_size` letters are
+/// skipped, so that a list can be shared by several word sizes. `name` is used
+/// in error messages.
+pub fn parse(
+    data: &[u8],
+    name: &str,
+    word_size: usize,
+    language: &Language,
+) -> Result<Dictionary, DictError> {
+    let mut words = Vec::new();
+    let mut report = LoadReport::default();
+    let mut invalid = Vec::new();
+
+    for (line, content) in lines(data) {
+        match content {
+            Line::Blank => report.blank_lines += 1,
+            Line::Comment => report.comments += 1,
+            Line::Invalid(bytes) => invalid.push(InvalidLine {
+                line,
+                content: String::from_utf8_lossy(bytes).into_owned(),
+                problem: Problem::Encoding,
+            }),
+            Line::Word(word) => match parse_word(word, word_size, language) {
+                Ok(word) => words.push(word),
+                Err(Problem::Length { .. }) => report.other_lengths += 1,
+                Err(problem) => invalid.push(InvalidLine {
+                    line,
+                    content: word.into(),
+                    problem,
+                }),
+            },
+        }
+    }
+
+    if !invalid.is_empty() {
+        return Err(DictError::InvalidLines {
+            name: name.into(),
+            lines: invalid,
+        });
+    }
+
+    let count = words.len();
+    words.sort_unstable();
+    words.dedup();
+    report.duplicates = count - words.len();
+    report.words = words.len();
+
+    if words.is_empty() {
+        return Err(DictError::Empty {
+            name: name.into(),
+            word_size,
+        });
+    }
+
+    Ok(Dictionary { words, report })
+}
+
+/// Loads the list of words stored in the file at `path`.
+pub fn load(path: &Path, word_size: usize, language: &Language) -> Result<Dictionary, DictError> {
+    let data = std::fs::read(path).map_err(|err| DictError::Io(path.into(), err))?;
+    parse(&data, &path.display().to_string(), word_size, language)
+}
@@ -0,0 +1,300 @@
+//! Implements the `wordle dict` maintenance commands.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::Path;
+
+use crate::cli::DictCommand;
+use crate::dict::{self, Line, Problem};
+use crate::game::{Letter, Word};
+use crate::language::Language;
+
+/// A word read from a word list, along with its line number.
+struct Entry {
+    line: usize,
+    /// The word, in lowercase.
+    word: String,
+    /// The letters of the word, used to compare words regardless of case and
+    /// accents.
+    key: Word,
+}
+
+fn read(path: &Path) -> Result<Vec<u8>, String> {
+    std::fs::read(path).map_err(|err| format!("failed to read '{}': {}", path.display(), err))
+}
+
+/// Converts `word` into letters of `language`, whatever its length.
+fn letters(word: &str, language: &Language) -> Result<Word, Problem> {
+    dict::parse_word(word, language.expand(word).count(), language)
+}
+
+/// Reads the words of a list, failing on the first invalid line.
+fn read_words(path: &Path, language: &Language) -> Result<Vec<Entry>, String> {
+    let data = read(path)?;
+    let mut entries = Vec::new();
+
+    for (line, content) in dict::lines(&data) {
+        match content {
+            Line::Blank | Line::Comment => (),
+            Line::Invalid(_) => {
+                return Err(format!("{}:{}: invalid UTF-8", path.display(), line));
+            }
+            Line::Word(word) => {
+                let key = letters(word, language)
+                    .map_err(|problem| format!("{}:{}: {}", path.display(), line, problem))?;
+                entries.push(Entry {
+                    line,
+                    word: word.to_lowercase(),
+                    key,
+                });
+            }
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Checks a word list, printing every problem found. Returns the number of
+/// problems.
+fn check_list(
+    path: &Path,
+    length: Option<usize>,
+    language: &Language,
+) -> Result<(Vec<Entry>, usize), String> {
+    let data = read(path)?;
+    let name = path.display();
+    let mut problems = 0;
+    let mut report = |line: usize, message: String| {
+        println!("{}:{}: {}", name, line, message);
+        problems += 1;
+    };
+
+    let mut entries = Vec::<Entry>::new();
+    let mut seen = HashMap::<Word, usize>::new();
+    let mut expected_length = length;
+
+    for (line, content) in dict::lines(&data) {
+        let word = match content {
+            Line::Blank | Line::Comment => continue,
+            Line::Invalid(_) => {
+                report(line, "invalid UTF-8".into());
+                continue;
+            }
+            Line::Word(word) => word,
+        };
+
+        let key = match letters(word, language) {
+            Ok(key) => key,
+            Err(problem) => {
+                report(line, format!("'{}': {}", word, problem));
+                continue;
+            }
+        };
+
+        let found = key.len();
+        let expected = *expected_length.get_or_insert(found);
+        if found != expected {
+            report(
+                line,
+                format!("'{}': {}", word, Problem::Length { expected, found }),
+            );
+        }
+
+        let word = word.to_lowercase();
+
+        if let Some(&first) = seen.get(&key) {
+            report(line, format!("'{}': duplicate of line {}", word, first));
+        } else {
+            seen.insert(key.clone(), line);
+        }
+
+        if let Some(previous) = entries.last() {
+            if key < previous.key {
+                report(
+                    line,
+                    format!("'{}': not sorted (comes after '{}')", word, previous.word),
+                );
+            }
+        }
+
+        entries.push(Entry { line, word, key });
+    }
+
+    Ok((entries, problems))
+}
+
+fn check(
+    words: &Path,
+    answers: Option<&Path>,
+    length: Option<usize>,
+    language: &Language,
+) -> Result<bool, String> {
+    let (entries, mut problems) = check_list(words, length, language)?;
+
+    if let Some(answers) = answers {
+        let length = length.or_else(|| entries.first().map(|e| e.key.len()));
+        let (answer_entries, answer_problems) = check_list(answers, length, language)?;
+        problems += answer_problems;
+
+        let guesses: BTreeSet<&Word> = entries.iter().map(|e| &e.key).collect();
+        for answer in &answer_entries {
+            if !guesses.contains(&answer.key) {
+                println!(
+                    "{}:{}: '{}': not in '{}'",
+                    answers.display(),
+                    answer.line,
+                    answer.word,
+                    words.display()
+                );
+                problems += 1;
+            }
+        }
+    }
+
+    println!("{} words, {} problem(s)", entries.len(), problems);
+    Ok(problems == 0)
+}
+
+/// Counts how many times each letter appears at each position of the words.
+fn letter_counts(entries: &[Entry]) -> BTreeMap<Letter, Vec<usize>> {
+    let positions = entries.iter().map(|e| e.key.len()).max().unwrap_or(0);
+
+    let mut counts = BTreeMap::<Letter, Vec<usize>>::new();
+    for entry in entries {
+        for (i, &letter) in entry.key.iter().enumerate() {
+            counts.entry(letter).or_insert_with(|| vec![0; positions])[i] += 1;
+        }
+    }
+    counts
+}
+
+fn stats(words: &Path, language: &Language) -> Result<bool, String> {
+    let entries = read_words(words, language)?;
+    let positions = entries.iter().map(|e| e.key.len()).max().unwrap_or(0);
+    let counts = letter_counts(&entries);
+
+    print!("letter");
+    for i in 0..positions {
+        print!(" {:>6}", i + 1);
+    }
+    println!(" {:>7}", "total");
+
+    for (letter, counts) in counts {
+        print!("{:>6}", language.char(letter));
+        for count in &counts {
+            print!(" {:>6}", count);
+        }
+        println!(" {:>7}", counts.iter().sum::<usize>());
+    }
+
+    println!("{} words", entries.len());
+    Ok(true)
+}
+
+/// Reads the words of a list, sorted in alphabet order and without
+/// duplicates.
+fn read_word_set(path: &Path, language: &Language) -> Result<BTreeMap<Word, String>, String> {
+    let mut words = BTreeMap::new();
+    for entry in read_words(path, language)? {
+        words.entry(entry.key).or_insert(entry.word);
+    }
+    Ok(words)
+}
+
+fn merge(lists: &[impl AsRef<Path>], language: &Language) -> Result<bool, String> {
+    let mut words = BTreeMap::new();
+    for list in lists {
+        for (key, word) in read_word_set(list.as_ref(), language)? {
+            words.entry(key).or_insert(word);
+        }
+    }
+
+    for word in words.values() {
+        println!("{}", word);
+    }
+    Ok(true)
+}
+
+fn diff(old: &Path, new: &Path, language: &Language) -> Result<bool, String> {
+    let old = read_word_set(old, language)?;
+    let new = read_word_set(new, language)?;
+
+    for (key, word) in &old {
+        if !new.contains_key(key) {
+            println!("- {}", word);
+        }
+    }
+    for (key, word) in &new {
+        if !old.contains_key(key) {
+            println!("+ {}", word);
+        }
+    }
+    Ok(true)
+}
+
+fn filter(words: &Path, length: usize, language: &Language) -> Result<bool, String> {
+    for entry in read_words(words, language)? {
+        if entry.key.len() == length {
+            println!("{}", entry.word);
+        }
+    }
+    Ok(true)
+}
+
+/// Runs a `wordle dict` command, returning the exit status of the process.
+pub fn run(command: DictCommand, language: &Language) -> i32 {
+    let result = match command {
+        DictCommand::Help => {
+            crate::cli::print_dict_help();
+            Ok(true)
+        }
+        DictCommand::Check {
+            words,
+            answers,
+            length,
+        } => check(&words, answers.as_deref(), length, language),
+        DictCommand::Stats { words } => stats(&words, language),
+        DictCommand::Merge { lists } => merge(&lists, language),
+        DictCommand::Diff { old, new } => diff(&old, &new, language),
+        DictCommand::Filter { words, length } => filter(&words, length, language),
+    };
+
+    match result {
+        Ok(true) => 0,
+        Ok(false) => 1,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            2
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::FRENCH;
+
+    #[test]
+    fn ligatures() {
+        let path =
+            std::env::temp_dir().join(format!("wordle-ligatures-{}.txt", std::process::id()));
+        let data = "abime\ncœur\n";
+        std::fs::write(&path, data).unwrap();
+        let checked = check_list(&path, Some(5), &FRENCH);
+        let read = read_words(&path, &FRENCH);
+        std::fs::remove_file(&path).unwrap();
+
+        // The tool counts the letters the way the game loads the words.
+        let loaded = dict::parse(data.as_bytes(), "list", 5, &FRENCH).unwrap();
+        assert_eq!(loaded.words.len(), 2);
+        let (entries, problems) = checked.unwrap();
+        assert_eq!(problems, 0);
+        let keys: Vec<_> = entries.into_iter().map(|e| e.key).collect();
+        assert_eq!(keys, loaded.words);
+
+        let counts = letter_counts(&read.unwrap());
+        let letter = |c| FRENCH.letter(c).unwrap();
+        assert_eq!(counts[&letter('O')], [0, 1, 0, 0, 0]);
+        assert_eq!(counts[&letter('E')], [0, 0, 1, 0, 1]);
+        assert_eq!(counts.len(), 9);
+    }
+}
@@ -1,216 +1,1169 @@
 use mlx::*;
 
+use crate::font::{Align, TextStyle};
 use crate::*;
 
-use Letter::*;
+/// Returns the square area of `size` pixels at (`x`, `y`).
+fn square(x: u32, y: u32, size: u32) -> Rect {
+    Rect::new(x, y, size, size)
+}
 
-const LAYOUT: [Letter; 26] = [
-    Q, W, E, R, T, Y, U, I, O, P, A, S, D, F, G, H, J, K, L, Z, X, C, V, B, N, M,
-];
+fn draw_square<C: Canvas>(size: u32, x: u32, y: u32, weight: u32, img: &mut C, color: Color) {
+    for j in 0..weight {
+        for i in 0..=size - 1 - j * 2 {
+            img.set_pixel(x + j + i, y + j, color);
+            img.set_pixel(x + j, y + i - j, color);
+            img.set_pixel(x + j + i, y - j + size - 1, color);
+            img.set_pixel(x - j + size - 1, y + j + i, color);
+        }
+    }
+}
 
-fn set_pixel(dst: &Image, dst_x: u32, dst_y: u32, grayscale: u8) {
-    assert!(dst_x < dst.width(), "invalid dst X value ({})", dst_x);
-    assert!(dst_y < dst.height(), "invalid dst Y value ({})", dst_y);
+fn draw_n_squares<C: Canvas>(img: &mut C, nb_col: usize, nb_row: usize, view: &View) {
+    let geometry = &view.geometry;
+    for col in 0..nb_col {
+        for row in 0..nb_row {
+            let (x, y) = geometry.tile(row, col);
+            let weight = geometry.px(2);
+            draw_square(geometry.tile_size, x, y, weight, img, view.theme.outline);
+        }
+    }
+}
 
-    unsafe {
-        let mut start = dst
-            .data()
-            .add((dst_y * dst.line_size() + dst_x * dst.bytes_per_pixel()) as usize);
-        for _ in 0..dst.bytes_per_pixel() {
-            *start = grayscale;
-            start = start.add(1);
+fn init_bg<C: Canvas>(img: &mut C, rules: &Rules, view: &View) {
+    for i in 0..img.width() {
+        for j in 0..img.height() {
+            img.set_pixel(i, j, view.theme.background);
         }
     }
+    draw_n_squares(img, rules.word_size, rules.max_tries, view);
+}
+
+/// A mark drawn above a sprite to form an accented letter.
+#[derive(Clone, Copy)]
+enum Mark {
+    Tilde,
+    Diaeresis,
+}
+
+/// How a letter is drawn using the A-Z sprite strips.
+enum Glyph {
+    /// A single sprite.
+    Sprite(u32),
+    /// A sprite with a mark above it.
+    Marked(u32, Mark),
+    /// Two sprites squeezed side by side.
+    Pair(u32, u32),
+    /// No sprite: the letter is drawn with the bitmap font on an empty tile.
+    Text(char),
 }
 
-fn copy_pixel(dst: &Image, dst_x: u32, dst_y: u32, mut pixel: *const u8) {
-    assert!(dst_x < dst.width(), "invalid dst X value ({})", dst_x);
-    assert!(dst_y < dst.height(), "invalid dst Y value ({})", dst_y);
+fn glyph(c: char) -> Glyph {
+    let sprite = |c: char| c as u32 - 'A' as u32;
 
-    unsafe {
-        let mut start = dst
-            .data()
-            .add((dst_y * dst.line_size() + dst_x * dst.bytes_per_pixel()) as usize);
-        for _ in 0..dst.bytes_per_pixel() {
-            *start = *pixel;
-            start = start.add(1);
-            pixel = pixel.add(1);
-        }
+    match c {
+        'A'..='Z' => Glyph::Sprite(sprite(c)),
+        'Ñ' => Glyph::Marked(sprite('N'), Mark::Tilde),
+        'Ä' => Glyph::Marked(sprite('A'), Mark::Diaeresis),
+        'Ö' => Glyph::Marked(sprite('O'), Mark::Diaeresis),
+        'Ü' => Glyph::Marked(sprite('U'), Mark::Diaeresis),
+        // The uppercase form of `ß` is `SS`.
+        'ß' => Glyph::Pair(sprite('S'), sprite('S')),
+        _ => Glyph::Text(c),
     }
 }
 
-fn draw_square(size: u32, x: u32, y: u32, weight: u32, img: &Image) {
-    for j in 0..weight {
-        for i in 0..=size - 1 - j * 2 {
-            set_pixel(img, x + j + i, y + j, 0xca);
-            set_pixel(img, x + j, y + i - j, 0xca);
-            set_pixel(img, x + j + i, y - j + size - 1, 0xca);
-            set_pixel(img, x - j + size - 1, y + j + i, 0xca);
+/// Copies the area `src` of `source` to the area `dst` of `destination`,
+/// scaling it.
+///
+/// Each pixel of `dst` is the average of the pixels of `src` it covers.
+fn copy_scaled<C: Canvas, S: Canvas>(source: &S, src: Rect, destination: &mut C, dst: Rect) {
+    if src.width == dst.width && src.height == dst.height {
+        let (x, y) = (dst.x as i32, dst.y as i32);
+        return destination.blit(source, src, x, y, Blend::Replace);
+    }
+
+    // The pixels of `src` covered by the pixel `i` of a side of `dst`.
+    let span = |start: u32, src_len: u32, dst_len: u32, i: u32| {
+        let from = start + i * src_len / dst_len;
+        let to = start + (i + 1) * src_len / dst_len;
+        from..u32::max(to, from + 1)
+    };
+
+    for j in 0..dst.height {
+        let rows = span(src.y, src.height, dst.height, j);
+        for i in 0..dst.width {
+            let mut sum = [0u32; 4];
+            let mut count = 0;
+            for y in rows.clone() {
+                for x in span(src.x, src.width, dst.width, i) {
+                    let p = source
+                        .get_pixel(x, y)
+                        .expect("source pixel outside of the image");
+                    for (total, channel) in sum.iter_mut().zip([p.r, p.g, p.b, p.a]) {
+                        *total += channel as u32;
+                    }
+                    count += 1;
+                }
+            }
+
+            let [r, g, b, a] = sum.map(|total| (total / count) as u8);
+            destination.set_pixel(dst.x + i, dst.y + j, Color::rgba(r, g, b, a));
         }
     }
 }
 
-fn draw_n_squares(img: &Image, nb_col: u32, nb_row: u32, size: u32) {
-    let mut x = 55;
-    let mut y;
-    for _ in 0..nb_col {
-        y = 30;
-        for __ in 0..nb_row {
-            draw_square(size, x, y, 2, img);
-            y = y + size + 10;
+/// Copies the columns `src_x..src_x + src_width` of the sprite number `index`
+/// to the area `dst` of `dst_img`.
+fn draw_sprite_part<C: Canvas, S: Canvas>(
+    index: u32,
+    src_x: u32,
+    src_width: u32,
+    dst: Rect,
+    dst_img: &mut C,
+    alphabet: &S,
+) {
+    let sprite = alphabet.height();
+    let src = Rect {
+        x: index * sprite + src_x,
+        y: 0,
+        width: src_width,
+        height: sprite,
+    };
+    copy_scaled(alphabet, src, dst_img, dst);
+}
+
+fn draw_sprite<C: Canvas, S: Canvas>(
+    index: u32,
+    dst_x: u32,
+    dst_y: u32,
+    dst_img: &mut C,
+    alphabet: &S,
+    size: u32,
+) {
+    let sprite = alphabet.height();
+    let dst = square(dst_x, dst_y, size);
+    draw_sprite_part(index, 0, sprite, dst, dst_img, alphabet);
+}
+
+/// Draws a mark above the sprite number `index`, previously drawn at (`dst_x`,
+/// `dst_y`). The mark takes the color of the letter.
+fn draw_mark<C: Canvas, S: Canvas>(
+    mark: Mark,
+    index: u32,
+    dst_x: u32,
+    dst_y: u32,
+    dst_img: &mut C,
+    alphabet: &S,
+    size: u32,
+) {
+    let luminance = |c: Color| c.r as i32 + c.g as i32 + c.b as i32;
+    let sprite = alphabet.height();
+    let sprite_pixel = |x: u32, y: u32| {
+        alphabet
+            .get_pixel(index * sprite + x, y)
+            .expect("sprite pixel outside of the image")
+    };
+
+    // The ink is the pixel of the sprite that contrasts most with its background.
+    let background = luminance(sprite_pixel(sprite / 2, sprite / 8));
+    let mut ink = sprite_pixel(0, 0);
+    for y in 0..sprite {
+        for x in 0..sprite {
+            let p = sprite_pixel(x, y);
+            if (luminance(p) - background).abs() > (luminance(ink) - background).abs() {
+                ink = p;
+            }
         }
-        x = x + size + 10;
     }
-}
 
-fn init_bg(img: &Image) {
-    for i in 0..img.width() {
-        for j in 0..img.height() {
-            set_pixel(img, i, j, 255);
+    let thickness = u32::max(size / 16, 2);
+    let mut dot = |x: u32, y: u32| {
+        dst_img.fill_rect(square(dst_x + x, dst_y + y, thickness), ink);
+    };
+
+    match mark {
+        Mark::Diaeresis => {
+            dot(size * 3 / 8 - thickness / 2, size * 5 / 64);
+            dot(size * 5 / 8 - thickness / 2, size * 5 / 64);
+        }
+        Mark::Tilde => {
+            let start = size * 5 / 16;
+            let width = size * 3 / 8;
+            let amplitude = (size / 32) as f32;
+            for x in 0..width {
+                let t = x as f32 / width as f32;
+                let offset = amplitude * (t * std::f32::consts::TAU).sin();
+                let y = (size * 6 / 64) as f32 - offset;
+                dot(start + x, y.round() as u32);
+            }
         }
     }
-    draw_n_squares(img, 5, 6, 64);
 }
 
-fn draw_letter(
-    letter: Letter,
+/// Returns the colors of the tiles and of the letters of `alphabet`, as found
+/// on the sprite of `A`.
+fn sprite_colors<S: Canvas>(alphabet: &S) -> (Color, Color) {
+    let size = alphabet.height();
+    let pixel = |x: u32, y: u32| alphabet.get_pixel(x, y).unwrap_or(Color::TRANSPARENT);
+    // The top of the sprite is above the letter.
+    let background = pixel(size / 2, size / 8);
+    let distance = |p: Color| {
+        [
+            (p.r, background.r),
+            (p.g, background.g),
+            (p.b, background.b),
+        ]
+        .into_iter()
+        .map(|(a, b)| a.abs_diff(b) as u32)
+        .sum::<u32>()
+    };
+    // The letter is what stands out the most from the tile.
+    let letter = (0..size)
+        .flat_map(|y| (0..size).map(move |x| (x, y)))
+        .map(|(x, y)| pixel(x, y))
+        .max_by_key(|&p| distance(p))
+        .unwrap_or(background);
+    (background, letter)
+}
+
+fn draw_letter<C: Canvas, S: Canvas>(
+    c: char,
     dst_x: u32,
     dst_y: u32,
-    dst_img: &Image,
-    alphabet: &Image,
+    dst_img: &mut C,
+    alphabet: &S,
     size: u32,
 ) {
-    let index = letter as u32;
-    let x_alphabet = index * size;
-    let y_alphabet = 0u32;
-    for y in 0..size {
-        for x in 0..size {
-            let color = unsafe {
-                alphabet.data().add(
-                    (alphabet.line_size() * (y_alphabet + y)
-                        + alphabet.bytes_per_pixel() * (x_alphabet + x))
-                        as usize,
-                )
+    match glyph(c) {
+        Glyph::Sprite(index) => draw_sprite(index, dst_x, dst_y, dst_img, alphabet, size),
+        Glyph::Marked(index, mark) => {
+            draw_sprite(index, dst_x, dst_y, dst_img, alphabet, size);
+            draw_mark(mark, index, dst_x, dst_y, dst_img, alphabet, size);
+        }
+        Glyph::Pair(left, right) => {
+            // Keep the borders of the tile, and squeeze its inside.
+            let sprite = alphabet.height();
+            let src_border = 2;
+            let border = u32::max(src_border * size / sprite, 1);
+            let half = (size - 2 * border) / 2;
+            let inside = |x: u32, width: u32| Rect {
+                x,
+                y: dst_y,
+                width,
+                height: size,
             };
-            copy_pixel(dst_img, dst_x + x, dst_y + y, color);
+
+            draw_sprite(left, dst_x, dst_y, dst_img, alphabet, size);
+            for (index, x, width) in [
+                (left, dst_x + border, half),
+                (right, dst_x + border + half, size - 2 * border - half),
+            ] {
+                let src_width = sprite - 2 * src_border;
+                draw_sprite_part(
+                    index,
+                    src_border,
+                    src_width,
+                    inside(x, width),
+                    dst_img,
+                    alphabet,
+                );
+            }
+        }
+        Glyph::Text(c) => {
+            // The tile is the one of `A`, without its letter.
+            let (background, color) = sprite_colors(alphabet);
+            let border = u32::max(2 * size / alphabet.height(), 1);
+            draw_sprite(0, dst_x, dst_y, dst_img, alphabet, size);
+            let inside = size - 2 * border;
+            fill_rect(
+                dst_img,
+                dst_x + border,
+                dst_y + border,
+                inside,
+                inside,
+                background,
+            );
+
+            let style = TextStyle {
+                scale: u32::max(size / 11, 1),
+                color,
+                align: Align::Center,
+            };
+            // The space below a line is left out to center the letter.
+            let height = style.line_height() - 3 * style.scale;
+            let x = (dst_x + size / 2) as i32;
+            let y = (dst_y + size.saturating_sub(height) / 2) as i32;
+            style.draw(dst_img, &c.to_string(), x, y);
         }
     }
 }
 
-fn draw_current(word: [Letter; 5], row: u32, cursor: usize, img: &Image, alphabet: &Image) {
-    let mut x = 55;
-    let y = 30 + row * 64 + row * 10;
-    for letter in &word[0..cursor] {
-        draw_letter(*letter, x, y, img, alphabet, 64);
-        x += 74;
+/// Returns the left, top, width and height of the tile at (`x`, `y`) once
+/// `transform` is applied around its center.
+fn transformed(x: u32, y: u32, geometry: &Geometry, transform: Transform) -> (i32, i32, i32, i32) {
+    let size = geometry.tile_size;
+    let width = (size as f32 * transform.scale_x).round() as i32;
+    let height = (size as f32 * transform.scale_y).round() as i32;
+    // Offsets are given at a scale of 1.
+    let dx = (transform.dx as f32 * geometry.scale).round() as i32;
+    let dy = (transform.dy as f32 * geometry.scale).round() as i32;
+    let left = x as i32 + (size as i32 - width) / 2 + dx;
+    let top = y as i32 + (size as i32 - height) / 2 + dy;
+    (left, top, width, height)
+}
+
+/// Redraws the tile at (`x`, `y`) with `transform` applied around its center.
+fn transform_tile<C: Canvas>(img: &mut C, x: u32, y: u32, view: &View, transform: Transform) {
+    if transform.scale_x == 1.0
+        && transform.scale_y == 1.0
+        && transform.dx == 0
+        && transform.dy == 0
+    {
+        return;
+    }
+
+    let geometry = &view.geometry;
+    let size = geometry.tile_size;
+    let mut tile = Vec::with_capacity((size * size) as usize);
+    for j in 0..size {
+        for i in 0..size {
+            tile.push(img.get_pixel(x + i, y + j).unwrap_or(view.theme.background));
+        }
+    }
+    fill_rect(img, x, y, size, size, view.theme.background);
+
+    let (left, top, width, height) = transformed(x, y, geometry, transform);
+
+    for j in 0..height {
+        for i in 0..width {
+            let (dst_x, dst_y) = (left + i, top + j);
+            if dst_x < 0 || dst_y < 0 || dst_x >= img.width() as i32 || dst_y >= img.height() as i32
+            {
+                continue;
+            }
+
+            let src_x = i as u32 * size / width as u32;
+            let src_y = j as u32 * size / height as u32;
+            let color = tile[(src_y * size + src_x) as usize];
+            img.set_pixel(dst_x as u32, dst_y as u32, color);
+        }
     }
 }
 
-fn draw_previous(word: [(Letter, Correctness); 5], row: u32, img: &Image, images: &Images) {
-    let mut x = 55;
-    let y = 30 + row * 64 + row * 10;
-    let mut alphabet: &Image;
-    for (letter, correctness) in word {
+fn draw_current<C: Canvas, S: Canvas>(
+    word: &[Letter],
+    row: usize,
+    cursor: usize,
+    language: &Language,
+    img: &mut C,
+    alphabet: &S,
+    view: &View,
+) {
+    let size = view.geometry.tile_size;
+    for (col, &letter) in word.iter().enumerate() {
+        let (x, y) = view.geometry.tile(row, col);
+        if col < cursor {
+            draw_letter(language.char(letter), x, y, img, alphabet, size);
+        }
+        // Empty tiles are animated as well, so that the whole row shakes.
+        transform_tile(img, x, y, view, view.animations.tile(row, col));
+    }
+}
+
+fn draw_previous<C: Canvas, S: Canvas>(
+    word: &[(Letter, Correctness)],
+    row: usize,
+    language: &Language,
+    img: &mut C,
+    images: &Images<S>,
+    view: &View,
+) {
+    let size = view.geometry.tile_size;
+    let mut alphabet: &S;
+    for (col, &(letter, correctness)) in word.iter().enumerate() {
+        let (x, y) = view.geometry.tile(row, col);
+        let transform = view.animations.tile(row, col);
         match correctness {
+            _ if !transform.revealed => alphabet = &images.black_letters,
             Correctness::Correct => alphabet = &images.green_letters,
             Correctness::Misplaced => alphabet = &images.yellow_letters,
             Correctness::Incorrect => alphabet = &images.grey_letters,
         }
-        draw_letter(letter, x, y, img, alphabet, 64);
-        x += 74;
+        draw_letter(language.char(letter), x, y, img, alphabet, size);
+        transform_tile(img, x, y, view, transform);
     }
 }
 
-fn copy_image(source: &Image, dst_x: u32, dst_y: u32, destination: &Image) {
-    for y in 0..source.height() {
-        for x in 0..source.width() {
-            copy_pixel(destination, dst_x + x, dst_y + y, unsafe {
-                source.data().add((x * source.bytes_per_pixel() + source.line_size() * y) as usize)
+fn draw_final_screen<C: Canvas>(
+    word: &[Letter],
+    language: &Language,
+    geometry: &Geometry,
+    target: &mut C,
+    image: &C,
+    alphabet: &C,
+) {
+    let width = geometry.px(image.width());
+    let src = Rect {
+        x: 0,
+        y: 0,
+        width: image.width(),
+        height: image.height(),
+    };
+    let dst = Rect {
+        x: (target.width() - width) / 2,
+        y: geometry.bottom_y,
+        width,
+        height: geometry.px(image.height()),
+    };
+    copy_scaled(image, src, target, dst);
+
+    let size = geometry.tile_size;
+    let x = target.width().saturating_sub(size * word.len() as u32) / 2;
+    for (i, &letter) in word.iter().enumerate() {
+        draw_letter(
+            language.char(letter),
+            x + size * i as u32,
+            geometry.bottom_y + geometry.px(46),
+            target,
+            alphabet,
+            size,
+        );
+    }
+}
+
+/// How a tile of the grid is drawn.
+#[derive(Clone, Copy, PartialEq)]
+pub struct TileState {
+    /// The letter of the tile, if any.
+    letter: Option<Letter>,
+    /// The color of the tile, once revealed.
+    correctness: Option<Correctness>,
+    transform: Transform,
+}
+
+/// Returns how the tiles of the grid are drawn, row by row.
+pub fn tile_states(game: &Game, view: &View) -> Vec<TileState> {
+    let (len, tries) = (game.rules.word_size, game.rules.max_tries);
+    let mut tiles = Vec::with_capacity(len * tries);
+    for row in 0..tries {
+        for col in 0..len {
+            let transform = view.animations.tile(row, col);
+            let (letter, correctness) = match row.cmp(&game.current_try) {
+                std::cmp::Ordering::Less => {
+                    let (letter, correctness) = game.previous_words[row][col];
+                    (Some(letter), transform.revealed.then_some(correctness))
+                }
+                std::cmp::Ordering::Equal if col < game.cursor => {
+                    (Some(game.current_word[col]), None)
+                }
+                _ => (None, None),
+            };
+            tiles.push(TileState {
+                letter,
+                correctness,
+                transform,
             });
         }
     }
+    tiles
 }
 
-fn draw_final_screen(
-    word: [Letter; Game::WORD_SIZE],
-    target: &Image,
-    image: &Image,
-    alphabet: &Image,
-) {
-    copy_image(image, 10, 494, target);
+/// Returns the area the tile at `row` and `col` covers when drawn as `tile`,
+/// including the place it leaves empty when it moves.
+pub fn tile_area(view: &View, row: usize, col: usize, tile: &TileState) -> Rect {
+    let geometry = &view.geometry;
+    let (x, y) = geometry.tile(row, col);
+    let size = geometry.tile_size;
+    let (left, top, width, height) = transformed(x, y, geometry, tile.transform);
+    let (left, top) = (left.max(0) as u32, top.max(0) as u32);
+    Rect::new(x, y, size, size).union(Rect::new(left, top, width as u32, height as u32))
+}
 
-    for (i, &letter) in word.iter().enumerate() {
-        draw_letter(letter, 75 + 64 * i as u32, 540, target, alphabet, 64);
+/// Presentation settings that are not part of the state of the game.
+pub struct View {
+    /// The layout of the on-screen keyboard.
+    pub layout: &'static Layout,
+    /// The key of the on-screen keyboard under the mouse pointer.
+    pub hovered: Option<KeyAction>,
+    /// The key of the on-screen keyboard the mouse button was pressed on.
+    pub pressed: Option<KeyAction>,
+    /// The animations of the tiles of the grid.
+    pub animations: Animations,
+    /// Where the elements of the game are drawn.
+    pub geometry: Geometry,
+    /// The colors the game is drawn with.
+    pub theme: &'static Theme,
+    /// The message shown above the grid, if any.
+    pub toast: Option<Toast>,
+    /// The screen shown over the game.
+    pub menu: Menu,
+    /// The results of the games played so far.
+    pub stats: Stats,
+}
+
+impl View {
+    /// Creates the view of a game played with `config`, in a window of
+    /// `width` by `height` pixels.
+    pub fn new(config: &Config, width: u32, height: u32) -> Self {
+        Self {
+            layout: config.layout(),
+            hovered: None,
+            pressed: None,
+            animations: Animations::new(config.reduced_motion),
+            geometry: Geometry::new(width, height, &config.rules),
+            theme: config.theme,
+            toast: None,
+            menu: Menu::new(config.clone()),
+            stats: Stats::new(config.rules.max_tries),
+        }
     }
 }
 
-fn draw_keyboard(img: &Image, images: &Images, game: &Game) {
-    let mut alphabet: &Image;
-    let mut x = 30u32;
-    let mut y = 494;
+/// A short message shown above the grid.
+pub struct Toast {
+    pub message: &'static str,
+    /// When the message disappears.
+    pub until: std::time::Instant,
+}
 
-    for &letter in &LAYOUT[0..10] {
-        match game.letters_state[letter as usize] {
-            Some(Correctness::Correct) => alphabet = &images.green_letters_32,
-            Some(Correctness::Misplaced) => alphabet = &images.yellow_letters_32,
-            Some(Correctness::Incorrect) => alphabet = &images.grey_letters_32,
-            None => alphabet = &images.black_letters_32,
+/// Returns the message explaining why a word was rejected.
+pub fn rejection_message(rejection: Rejection) -> &'static str {
+    match rejection {
+        Rejection::Incomplete => "Not enough letters",
+        Rejection::Unknown => "Not in word list",
+        Rejection::MissingHints => "Every revealed hint must be used",
+    }
+}
+
+/// Draws the number of the current try and the language above the grid.
+fn draw_header<C: Canvas>(img: &mut C, game: &Game, view: &View) {
+    let geometry = &view.geometry;
+    let mut style = TextStyle {
+        scale: geometry.px(2),
+        color: view.theme.outline,
+        align: Align::Left,
+    };
+    let top = geometry.grid_y as i32 - geometry.px(22) as i32;
+    let (left, _) = geometry.tile(0, 0);
+    let (right, _) = geometry.tile(0, game.rules.word_size - 1);
+    let right = right + geometry.tile_size;
+
+    // Once the game is over, the last try is the one that ended it.
+    let try_number = match game.state {
+        GameState::Playing => game.current_try + 1,
+        GameState::Won | GameState::Lost => game.current_try,
+    };
+    let tries = format!("{}/{}", try_number, game.rules.max_tries);
+    style.draw(img, &tries, left as i32, top);
+
+    style.align = Align::Right;
+    style.draw(img, game.language.name, right as i32, top);
+}
+
+/// Draws the toast of `view` over the top of the grid.
+fn draw_toast<C: Canvas>(img: &mut C, view: &View) {
+    let toast = match &view.toast {
+        Some(toast) => toast,
+        None => return,
+    };
+
+    let geometry = &view.geometry;
+    let style = TextStyle {
+        scale: geometry.px(2),
+        color: view.theme.background,
+        align: Align::Center,
+    };
+    let padding = geometry.px(10);
+    let lines = style.wrap(toast.message, geometry.keyboard_width - 2 * padding);
+    let text_width = lines
+        .iter()
+        .map(|line| style.width(line))
+        .max()
+        .unwrap_or(0);
+    // The space below the last line is not needed.
+    let text_height = lines.len() as u32 * style.line_height() - 3 * style.scale;
+
+    let width = text_width + 2 * padding;
+    let height = text_height + 2 * padding;
+    let x = (img.width() - width) / 2;
+    let y = geometry.grid_y + geometry.px(12);
+    fill_rect(img, x, y, width, height, view.theme.ink);
+
+    let center = (img.width() / 2) as i32;
+    for (i, line) in lines.iter().enumerate() {
+        let top = y + padding + i as u32 * style.line_height();
+        style.draw(img, line, center, top as i32);
+    }
+}
+
+/// A key of the on-screen keyboard.
+pub struct Key {
+    pub action: KeyAction,
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+}
+
+fn key_width(action: KeyAction, geometry: &Geometry) -> u32 {
+    match action {
+        KeyAction::Letter(_) => geometry.key_size,
+        KeyAction::Enter | KeyAction::Backspace => geometry.wide_key_width,
+    }
+}
+
+/// Computes the position of the keys of the on-screen keyboard.
+pub fn keyboard_keys(game: &Game, view: &View) -> Vec<Key> {
+    let geometry = &view.geometry;
+    let left = geometry.keyboard_x;
+    let mut y = geometry.bottom_y;
+    let mut keys = Vec::new();
+
+    let rows = view.layout.rows(game.language);
+    let last = rows.len().saturating_sub(1);
+
+    for (i, row) in rows.into_iter().enumerate() {
+        let mut actions: Vec<KeyAction> = row.letters.into_iter().map(KeyAction::Letter).collect();
+        // Like on a phone, Enter and Backspace surround the last row.
+        if i == last {
+            actions.insert(0, KeyAction::Enter);
+            actions.push(KeyAction::Backspace);
+        }
+
+        // The keys of rows that would not fit are squeezed together.
+        let widths: u32 = actions
+            .iter()
+            .map(|&action| key_width(action, geometry))
+            .sum();
+        let gaps = actions.len().saturating_sub(1) as u32;
+        let gap = match gaps {
+            0 => geometry.key_gap,
+            _ => u32::min(
+                geometry.key_gap,
+                geometry.keyboard_width.saturating_sub(widths) / gaps,
+            ),
+        };
+
+        let max = geometry.keyboard_width as i32 - (widths + gaps * gap) as i32;
+        let offset = (row.offset as f32 * geometry.scale).round() as i32;
+        let mut x = left + (max / 2 + offset).clamp(0, max.max(0)) as u32;
+
+        for action in actions {
+            let width = key_width(action, geometry);
+            keys.push(Key {
+                action,
+                x,
+                y,
+                width,
+            });
+            x += width + gap;
         }
-        draw_letter(letter, x, y, img, alphabet, 32);
-        x += 42;
+        y += geometry.key_size + geometry.key_gap;
     }
-    y += 42;
-    x = 51;
-    for &letter in &LAYOUT[10..19] {
-        match game.letters_state[letter as usize] {
-            Some(Correctness::Correct) => alphabet = &images.green_letters_32,
-            Some(Correctness::Misplaced) => alphabet = &images.yellow_letters_32,
-            Some(Correctness::Incorrect) => alphabet = &images.grey_letters_32,
-            None => alphabet = &images.black_letters_32,
+
+    keys
+}
+
+/// Returns the key of the on-screen keyboard at (`x`, `y`).
+///
+/// When the game is over, the whole area below the grid acts as the Enter key,
+/// so that a new game can be started with the mouse.
+pub fn key_at(game: &Game, view: &View, x: i32, y: i32) -> Option<KeyAction> {
+    if game.state != GameState::Playing {
+        return (y >= view.geometry.bottom_y as i32).then_some(KeyAction::Enter);
+    }
+
+    let height = view.geometry.key_size;
+    keyboard_keys(game, view)
+        .into_iter()
+        .find(|key| {
+            (key.x as i32..(key.x + key.width) as i32).contains(&x)
+                && (key.y as i32..(key.y + height) as i32).contains(&y)
+        })
+        .map(|key| key.action)
+}
+
+fn fill_rect<C: Canvas>(img: &mut C, x: u32, y: u32, width: u32, height: u32, color: Color) {
+    img.fill_rect(Rect::new(x, y, width, height), color);
+}
+
+fn draw_frame<C: Canvas>(
+    img: &mut C,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+    weight: u32,
+    color: Color,
+) {
+    fill_rect(img, x, y, width, weight, color);
+    fill_rect(img, x, y + height - weight, width, weight, color);
+    fill_rect(img, x, y, weight, height, color);
+    fill_rect(img, x + width - weight, y, weight, height, color);
+}
+
+/// Tints an area of `img` with `color`, to show a key being pressed.
+fn shade_rect<C: Canvas>(img: &mut C, x: u32, y: u32, width: u32, height: u32, color: Color) {
+    for j in y..y + height {
+        for i in x..x + width {
+            if let Some(current) = img.get_pixel(i, j) {
+                img.set_pixel(i, j, current.mix(color, 0.25));
+            }
         }
-        draw_letter(letter, x, y, img, alphabet, 32);
-        x += 42;
     }
-    y += 42;
-    x = 93;
-    for &letter in &LAYOUT[19..26] {
-        match game.letters_state[letter as usize] {
+}
+
+/// Draws an arrow pointing left, with its tip at (`x`, `y`). The length is
+/// given at a scale of 1.
+fn draw_arrow<C: Canvas>(
+    img: &mut C,
+    x: u32,
+    y: u32,
+    length: u32,
+    geometry: &Geometry,
+    color: Color,
+) {
+    let thickness = geometry.px(2);
+    let top = y - thickness / 2;
+
+    fill_rect(img, x, top, geometry.px(length), thickness, color);
+    for i in 0..geometry.px(7) {
+        fill_rect(img, x + i, top - i, thickness, 1, color);
+        fill_rect(img, x + i, top + thickness - 1 + i, thickness, 1, color);
+    }
+}
+
+/// Draws the Enter or Backspace key, which have no sprite.
+fn draw_wide_key<C: Canvas>(img: &mut C, key: &Key, view: &View) {
+    let geometry = &view.geometry;
+    let theme = view.theme;
+    let height = geometry.key_size;
+    fill_rect(img, key.x, key.y, key.width, height, theme.tile);
+    let weight = geometry.px(1);
+    draw_frame(
+        img,
+        key.x,
+        key.y,
+        key.width,
+        height,
+        weight,
+        theme.key_border,
+    );
+
+    let center_x = key.x + key.width / 2;
+    let center_y = key.y + height / 2;
+    let left = center_x - geometry.px(10);
+    draw_arrow(img, left, center_y, 20, geometry, theme.ink);
+    if key.action == KeyAction::Enter {
+        // The arrow of Enter comes down from the top right, like `↵`.
+        let bar = geometry.px(9);
+        let x = center_x + geometry.px(8);
+        fill_rect(img, x, center_y - bar, geometry.px(2), bar, theme.ink);
+    }
+}
+
+fn draw_keyboard<C: Canvas>(img: &mut C, images: &Images<C>, game: &Game, view: &View) {
+    let geometry = &view.geometry;
+    let mut alphabet: &C;
+
+    for key in keyboard_keys(game, view) {
+        let letter = match key.action {
+            KeyAction::Letter(letter) => letter,
+            _ => {
+                draw_wide_key(img, &key, view);
+                continue;
+            }
+        };
+
+        match game.letters_state[letter.0 as usize] {
             Some(Correctness::Correct) => alphabet = &images.green_letters_32,
             Some(Correctness::Misplaced) => alphabet = &images.yellow_letters_32,
             Some(Correctness::Incorrect) => alphabet = &images.grey_letters_32,
             None => alphabet = &images.black_letters_32,
         }
-        draw_letter(letter, x, y, img, alphabet, 32);
-        x += 42;
+        draw_letter(
+            game.language.char(letter),
+            key.x,
+            key.y,
+            img,
+            alphabet,
+            geometry.key_size,
+        );
+    }
+
+    // Feedback is drawn over the keys.
+    let height = geometry.key_size;
+    for key in keyboard_keys(game, view) {
+        if view.pressed == Some(key.action) && view.hovered == Some(key.action) {
+            shade_rect(img, key.x, key.y, key.width, height, view.theme.ink);
+        } else if view.hovered == Some(key.action) {
+            let weight = geometry.px(2);
+            draw_frame(
+                img,
+                key.x,
+                key.y,
+                key.width,
+                height,
+                weight,
+                view.theme.hover,
+            );
+        }
     }
 }
 
-pub fn draw(game: &Game, output: &Image, images: &Images) {
-    init_bg(output);
+pub fn draw<C: Canvas>(game: &Game, view: &View, output: &mut C, images: &Images<C>) {
+    init_bg(output, &game.rules, view);
+    draw_header(output, game, view);
     for i in 0..game.current_try {
-        draw_previous(game.previous_words[i], i as u32, output, images);
-    }
-    draw_current(
-        game.current_word,
-        game.current_try as u32,
-        game.cursor,
-        output,
-        &images.black_letters,
-    );
+        draw_previous(
+            &game.previous_words[i],
+            i,
+            game.language,
+            output,
+            images,
+            view,
+        );
+    }
+    if game.current_try < game.rules.max_tries {
+        draw_current(
+            &game.current_word,
+            game.current_try,
+            game.cursor,
+            game.language,
+            output,
+            &images.black_letters,
+            view,
+        );
+    }
 
     match game.state {
-        GameState::Playing => draw_keyboard(output, images, game),
+        GameState::Playing => draw_keyboard(output, images, game, view),
         GameState::Lost => draw_final_screen(
-            game.winning_word,
+            &game.winning_word,
+            game.language,
+            &view.geometry,
             output,
             &images.lost_final_screen,
             &images.winning_letters,
         ),
         GameState::Won => draw_final_screen(
-            game.winning_word,
+            &game.winning_word,
+            game.language,
+            &view.geometry,
             output,
             &images.won_final_screen,
             &images.winning_letters,
         ),
     }
+
+    if view.menu.screen == Screen::Playing {
+        draw_toast(output, view);
+    } else {
+        draw_screen(output, images, game, view);
+    }
+}
+
+/// Draws the grid again in `area`, leaving the rest of `output` as it is.
+///
+/// This is enough when only tiles changed, and `area` covers them both as
+/// they were drawn and as they are now. Returns `false`, without drawing
+/// anything, if `area` reaches what is drawn below the grid or if a screen
+/// hides the grid: the whole game must then be drawn again.
+pub fn draw_grid_area<C: Canvas>(
+    game: &Game,
+    view: &View,
+    output: &mut C,
+    images: &Images<C>,
+    area: Rect,
+) -> bool {
+    let geometry = &view.geometry;
+    if area.y + area.height > geometry.bottom_y || view.menu.screen != Screen::Playing {
+        return false;
+    }
+
+    let mut img = Clip::new(output, area);
+    fill_rect(
+        &mut img,
+        area.x,
+        area.y,
+        area.width,
+        area.height,
+        view.theme.background,
+    );
+    draw_n_squares(&mut img, game.rules.word_size, game.rules.max_tries, view);
+    draw_header(&mut img, game, view);
+
+    // Tiles that moved out of their row changed, so their row is in the area.
+    let rows = (0..game.rules.max_tries).filter(|&row| {
+        let (_, y) = geometry.tile(row, 0);
+        y < area.y + area.height && area.y < y + geometry.tile_size
+    });
+    for row in rows {
+        if row < game.current_try {
+            let word = &game.previous_words[row];
+            draw_previous(word, row, game.language, &mut img, images, view);
+        } else if row == game.current_try {
+            let word = &game.current_word;
+            let (cursor, alphabet) = (game.cursor, &images.black_letters);
+            draw_current(word, row, cursor, game.language, &mut img, alphabet, view);
+        }
+    }
+
+    draw_toast(&mut img, view);
+    true
+}
+
+/// The height of a row of a screen, at a scale of 1.
+const ROW_HEIGHT: u32 = 44;
+/// The space between two rows of a screen, at a scale of 1.
+const ROW_GAP: u32 = 10;
+
+/// Returns the area of the row at `index` of the current screen.
+fn screen_row(view: &View, index: usize) -> Rect {
+    let geometry = &view.geometry;
+    let height = geometry.px(ROW_HEIGHT);
+    // Screens with some content only have a row at the bottom of the window.
+    let top = match view.menu.screen {
+        Screen::Stats | Screen::Help => geometry.height.saturating_sub(geometry.px(30) + height),
+        _ => geometry.grid_y + geometry.px(80),
+    };
+
+    Rect {
+        x: geometry.keyboard_x,
+        y: top + index as u32 * (height + geometry.px(ROW_GAP)),
+        width: geometry.keyboard_width,
+        height,
+    }
+}
+
+/// Returns the index of the row of the current screen at (`x`, `y`).
+pub fn screen_row_at(view: &View, x: i32, y: i32) -> Option<usize> {
+    let (x, y) = (u32::try_from(x).ok()?, u32::try_from(y).ok()?);
+    (0..view.menu.screen.entries().len()).find(|&index| {
+        let row = screen_row(view, index);
+        (row.x..row.x + row.width).contains(&x) && (row.y..row.y + row.height).contains(&y)
+    })
+}
+
+/// Draws the statistics below the title of the stats screen.
+fn draw_stats<C: Canvas>(img: &mut C, game: &Game, view: &View, top: u32) {
+    let geometry = &view.geometry;
+    let stats = &view.stats;
+    let theme = view.theme;
+    let mut style = TextStyle {
+        scale: geometry.px(4),
+        color: theme.ink,
+        align: Align::Center,
+    };
+    let small = TextStyle {
+        scale: geometry.px(2),
+        ..style
+    };
+
+    let numbers = [
+        (stats.played, "Played"),
+        (stats.win_rate(), "Win %"),
+        (stats.streak, "Streak"),
+        (stats.best_streak, "Best"),
+    ];
+    let column = geometry.keyboard_width / numbers.len() as u32;
+    for (i, (number, label)) in numbers.into_iter().enumerate() {
+        let x = (geometry.keyboard_x + column * i as u32 + column / 2) as i32;
+        style.draw(img, &number.to_string(), x, top as i32);
+        small.draw(img, label, x, (top + style.line_height()) as i32);
+    }
+
+    let mut y = top + style.line_height() + geometry.px(40);
+    style.scale = geometry.px(2);
+    small.draw(
+        img,
+        "Guess distribution",
+        (img.width() / 2) as i32,
+        y as i32,
+    );
+    y += small.line_height() + geometry.px(6);
+
+    // Bars are proportional to the most frequent number of tries.
+    let most = stats.distribution.iter().copied().max().unwrap_or(0).max(1);
+    let label_width = geometry.px(20);
+    let bar_space = geometry.keyboard_width - label_width;
+    let height = geometry.px(20);
+    let bottom = screen_row(view, 0).y;
+    for (i, &count) in stats.distribution.iter().enumerate() {
+        if y + height > bottom {
+            break;
+        }
+        let x = geometry.keyboard_x;
+        let text_y = (y + (height - geometry.px(14)) / 2) as i32;
+        style.align = Align::Left;
+        style.color = theme.ink;
+        style.draw(img, &(i + 1).to_string(), x as i32, text_y);
+
+        // The bar of the last win is highlighted.
+        let last = game.state == GameState::Won && game.current_try == i + 1;
+        let color = if last { theme.correct } else { theme.incorrect };
+        let width = u32::max(bar_space * count / most, geometry.px(24));
+        fill_rect(img, x + label_width, y, width, height, color);
+        style.align = Align::Right;
+        style.color = theme.scored_ink;
+        let end = (x + label_width + width - geometry.px(4)) as i32;
+        style.draw(img, &count.to_string(), end, text_y);
+
+        y += height + geometry.px(4);
+    }
+}
+
+/// The examples of the help screen: a guess, the correctness of its letters,
+/// and what it shows.
+///
+/// Letters are marked `c` when correct, `m` when misplaced, `i` when
+/// incorrect, and `.` when not scored.
+const HELP_EXAMPLES: [(&str, &str, &str); 4] = [
+    ("WEARY", "c....", "W is in the word, in the right spot."),
+    ("PILLS", ".m...", "I is in the word, in another spot."),
+    ("VAGUE", "...i.", "U is not in the word."),
+    (
+        "LEVEL",
+        "iiicc",
+        "Against HOTEL, the extra L and E are grey: each letter is only \
+         colored as many times as it is in the word.",
+    ),
+];
+
+/// Draws the rules of the game below the title of the help screen.
+fn draw_help<C: Canvas>(img: &mut C, images: &Images<C>, game: &Game, view: &View, top: u32) {
+    let geometry = &view.geometry;
+    let style = TextStyle {
+        scale: geometry.px(2),
+        color: view.theme.ink,
+        align: Align::Left,
+    };
+    let x = geometry.keyboard_x;
+    let width = geometry.keyboard_width;
+    // Whatever does not fit above the rows of the screen is not drawn.
+    let bottom = screen_row(view, 0).y - geometry.px(10);
+    let mut y = top;
+
+    let paragraph = |img: &mut C, text: &str, y: &mut u32| {
+        for line in style.wrap(text, width) {
+            if *y + style.line_height() > bottom {
+                return;
+            }
+            style.draw(img, line, x as i32, *y as i32);
+            *y += style.line_height();
+        }
+        *y += geometry.px(6);
+    };
+
+    let intro = format!(
+        "Guess the word in {} tries. Each guess must be a valid {}-letter word.",
+        game.rules.max_tries, game.rules.word_size,
+    );
+    paragraph(img, &intro, &mut y);
+
+    let size = geometry.px(36);
+    let gap = geometry.px(4);
+    for (word, scores, caption) in HELP_EXAMPLES {
+        if y + size > bottom {
+            return;
+        }
+        for (i, (c, score)) in word.chars().zip(scores.chars()).enumerate() {
+            let alphabet = match score {
+                'c' => &images.green_letters,
+                'm' => &images.yellow_letters,
+                'i' => &images.grey_letters,
+                _ => &images.black_letters,
+            };
+            draw_letter(c, x + i as u32 * (size + gap), y, img, alphabet, size);
+        }
+        y += size + geometry.px(6);
+        paragraph(img, caption, &mut y);
+    }
+
+    if game.rules.hard {
+        paragraph(
+            img,
+            "Hard mode: the revealed hints must be used in every later guess.",
+            &mut y,
+        );
+    }
+    match view.menu.config.mode {
+        Mode::Random => paragraph(img, "A new word is picked for every game.", &mut y),
+        Mode::Daily => paragraph(
+            img,
+            "Daily mode: everyone gets the same word each day.",
+            &mut y,
+        ),
+    }
+    paragraph(
+        img,
+        "F1 or ? shows this help, Escape opens the menu, and F12 saves a screenshot.",
+        &mut y,
+    );
+}
+
+/// Draws the current screen over the whole window.
+fn draw_screen<C: Canvas>(img: &mut C, images: &Images<C>, game: &Game, view: &View) {
+    let geometry = &view.geometry;
+    let theme = view.theme;
+    let screen = view.menu.screen;
+    fill_rect(img, 0, 0, img.width(), img.height(), theme.background);
+
+    let title = TextStyle {
+        scale: geometry.px(4),
+        color: theme.ink,
+        align: Align::Center,
+    };
+    title.draw(
+        img,
+        screen.title(),
+        (img.width() / 2) as i32,
+        geometry.grid_y as i32,
+    );
+
+    let top = geometry.grid_y + geometry.px(80);
+    match screen {
+        Screen::Stats => draw_stats(img, game, view, top),
+        Screen::Help => draw_help(img, images, game, view, top - geometry.px(30)),
+        _ => (),
+    }
+
+    let padding = geometry.px(12) as i32;
+    for (index, &entry) in screen.entries().iter().enumerate() {
+        let row = screen_row(view, index);
+        let mut style = TextStyle {
+            scale: geometry.px(2),
+            color: theme.ink,
+            align: Align::Center,
+        };
+        if index == view.menu.selected {
+            fill_rect(img, row.x, row.y, row.width, row.height, theme.ink);
+            style.color = theme.background;
+        } else {
+            fill_rect(img, row.x, row.y, row.width, row.height, theme.tile);
+            let weight = geometry.px(2);
+            draw_frame(
+                img,
+                row.x,
+                row.y,
+                row.width,
+                row.height,
+                weight,
+                theme.outline,
+            );
+        }
+
+        let y = (row.y + (row.height - geometry.px(14)) / 2) as i32;
+        let (left, right) = (row.x as i32, (row.x + row.width) as i32);
+        match entry {
+            Entry::Setting(setting) => {
+                style.align = Align::Left;
+                style.draw(img, entry.label(), left + padding, y);
+                style.align = Align::Right;
+                let value = setting.value(&view.menu.config);
+                style.draw(img, &value, right - padding, y);
+            }
+            _ => style.draw(img, entry.label(), (left + right) / 2, y),
+        }
+    }
 }
@@ -0,0 +1,35 @@
+//! Data files embedded in the executable when it is built with the `embed`
+//! feature. They are only used when no file is found on disk.
+
+/// The content of `words.txt`.
+pub const WORDS: &[u8] = include_bytes!("../words.txt");
+
+macro_rules! embed_assets {
+    ( $( $name:literal ),* $(,)? ) => {
+        /// The files of the `assets` directory, by name.
+        const ASSETS: &[(&str, &[u8])] = &[
+            $( ($name, include_bytes!(concat!("../assets/", $name))), )*
+        ];
+    };
+}
+
+embed_assets! {
+    "alphabet_neutral.xpm",
+    "alphabet_correct.xpm",
+    "alphabet_misplaced.xpm",
+    "alphabet_incorrect.xpm",
+    "alphabet_neutral_keyboard.xpm",
+    "alphabet_correct_keyboard.xpm",
+    "alphabet_misplaced_keyboard.xpm",
+    "alphabet_incorrect_keyboard.xpm",
+    "you_won.xpm",
+    "you_lost.xpm",
+}
+
+/// Returns the content of the embedded asset named `name`.
+pub fn asset(name: &str) -> Option<&'static [u8]> {
+    ASSETS
+        .iter()
+        .find(|(asset, _)| *asset == name)
+        .map(|(_, data)| *data)
+}
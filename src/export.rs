@@ -0,0 +1,111 @@
+//! Writes canvases to image files, so that the board can be shared outside of
+//! the game.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::canvas::Canvas;
+use crate::zlib;
+
+/// An error that might occur whilst writing an image file.
+#[derive(Debug)]
+pub enum ExportError {
+    /// The extension of the file is not the one of a supported format.
+    UnknownFormat(PathBuf),
+    /// The file could not be written.
+    Io(PathBuf, std::io::Error),
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnknownFormat(path) => write!(
+                f,
+                "cannot tell the format of '{}' (expected a '.png' or '.ppm' file)",
+                path.display()
+            ),
+            Self::Io(path, err) => write!(f, "failed to write '{}': {}", path.display(), err),
+        }
+    }
+}
+
+/// The formats images can be written in.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Png,
+    Ppm,
+}
+
+impl Format {
+    /// Tells the format of the file at `path` from its extension.
+    pub fn of(path: &Path) -> Result<Self, ExportError> {
+        let extension = path.extension().and_then(|extension| extension.to_str());
+        match extension.map(str::to_ascii_lowercase).as_deref() {
+            Some("png") => Ok(Self::Png),
+            Some("ppm") => Ok(Self::Ppm),
+            _ => Err(ExportError::UnknownFormat(path.into())),
+        }
+    }
+}
+
+/// Returns the red, green and blue channels of the pixels of `canvas`, row
+/// by row. Transparency is ignored.
+fn rgb<C: Canvas>(canvas: &C) -> Vec<u8> {
+    let mut data = Vec::with_capacity((canvas.width() * canvas.height() * 3) as usize);
+    for y in 0..canvas.height() {
+        for x in 0..canvas.width() {
+            let p = canvas.get_pixel(x, y).unwrap();
+            data.extend([p.r, p.g, p.b]);
+        }
+    }
+    data
+}
+
+/// Encodes `canvas` as a binary PPM image.
+fn ppm<C: Canvas>(canvas: &C) -> Vec<u8> {
+    let mut data = format!("P6\n{} {}\n255\n", canvas.width(), canvas.height()).into_bytes();
+    data.extend(rgb(canvas));
+    data
+}
+
+/// Encodes `canvas` as a PNG image.
+pub fn png<C: Canvas>(canvas: &C) -> Vec<u8> {
+    let chunk = |data: &mut Vec<u8>, name: &[u8], content: &[u8]| {
+        let start = data.len() + 4;
+        data.extend((content.len() as u32).to_be_bytes());
+        data.extend(name);
+        data.extend(content);
+        let crc = zlib::crc32(&data[start..]);
+        data.extend(crc.to_be_bytes());
+    };
+
+    let mut header = Vec::with_capacity(13);
+    header.extend(canvas.width().to_be_bytes());
+    header.extend(canvas.height().to_be_bytes());
+    // 8 bits per channel, red, green and blue, no interlacing.
+    header.extend([8, 2, 0, 0, 0]);
+
+    // Each row starts with the filter it uses, none here.
+    let stride = canvas.width() as usize * 3;
+    let mut rows = Vec::with_capacity((stride + 1) * canvas.height() as usize);
+    for row in rgb(canvas).chunks_exact(stride) {
+        rows.push(0);
+        rows.extend(row);
+    }
+
+    let mut data = b"\x89PNG\r\n\x1a\n".to_vec();
+    chunk(&mut data, b"IHDR", &header);
+    chunk(&mut data, b"IDAT", &zlib::compress(&rows));
+    chunk(&mut data, b"IEND", &[]);
+    data
+}
+
+/// Writes `canvas` to the file at `path`, as a PNG or PPM image depending on
+/// its extension.
+pub fn save<C: Canvas>(canvas: &C, path: &Path) -> Result<(), ExportError> {
+    let data = match Format::of(path)? {
+        Format::Png => png(canvas),
+        Format::Ppm => ppm(canvas),
+    };
+    std::fs::write(path, data).map_err(|err| ExportError::Io(path.into(), err))
+}
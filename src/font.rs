@@ -0,0 +1,265 @@
+//! Draws text with a built-in bitmap font.
+//!
+//! Every glyph is 5 by 7 pixels, and is scaled by an integer factor to get
+//! larger text.
+
+use mlx::Color;
+
+use crate::canvas::Canvas;
+
+/// The width of a glyph, at a scale of 1.
+const GLYPH_WIDTH: u32 = 5;
+/// The height of a glyph, at a scale of 1.
+const GLYPH_HEIGHT: u32 = 7;
+/// The horizontal space taken by a character, including the space after it.
+const ADVANCE: u32 = GLYPH_WIDTH + 1;
+/// The vertical space taken by a line of text.
+const LINE_HEIGHT: u32 = GLYPH_HEIGHT + 3;
+
+/// The glyphs of the printable ASCII characters, from `' '` to `'~'`.
+///
+/// Each row is stored in the five low bits of a byte, the leftmost pixel
+/// being the highest bit.
+const GLYPHS: [[u8; GLYPH_HEIGHT as usize]; 95] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // ' '
+    [0x04, 0x04, 0x04, 0x04, 0x04, 0x00, 0x04], // '!'
+    [0x0a, 0x0a, 0x0a, 0x00, 0x00, 0x00, 0x00], // '"'
+    [0x0a, 0x0a, 0x1f, 0x0a, 0x1f, 0x0a, 0x0a], // '#'
+    [0x04, 0x0f, 0x14, 0x0e, 0x05, 0x1e, 0x04], // '$'
+    [0x18, 0x19, 0x02, 0x04, 0x08, 0x13, 0x03], // '%'
+    [0x0c, 0x12, 0x14, 0x08, 0x15, 0x12, 0x0d], // '&'
+    [0x04, 0x04, 0x04, 0x00, 0x00, 0x00, 0x00], // '\''
+    [0x02, 0x04, 0x08, 0x08, 0x08, 0x04, 0x02], // '('
+    [0x08, 0x04, 0x02, 0x02, 0x02, 0x04, 0x08], // ')'
+    [0x00, 0x04, 0x15, 0x0e, 0x15, 0x04, 0x00], // '*'
+    [0x00, 0x04, 0x04, 0x1f, 0x04, 0x04, 0x00], // '+'
+    [0x00, 0x00, 0x00, 0x00, 0x0c, 0x04, 0x08], // ','
+    [0x00, 0x00, 0x00, 0x1f, 0x00, 0x00, 0x00], // '-'
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x0c], // '.'
+    [0x00, 0x01, 0x02, 0x04, 0x08, 0x10, 0x00], // '/'
+    [0x0e, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0e], // '0'
+    [0x04, 0x0c, 0x04, 0x04, 0x04, 0x04, 0x0e], // '1'
+    [0x0e, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1f], // '2'
+    [0x1f, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0e], // '3'
+    [0x02, 0x06, 0x0a, 0x12, 0x1f, 0x02, 0x02], // '4'
+    [0x1f, 0x10, 0x1e, 0x01, 0x01, 0x11, 0x0e], // '5'
+    [0x06, 0x08, 0x10, 0x1e, 0x11, 0x11, 0x0e], // '6'
+    [0x1f, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08], // '7'
+    [0x0e, 0x11, 0x11, 0x0e, 0x11, 0x11, 0x0e], // '8'
+    [0x0e, 0x11, 0x11, 0x0f, 0x01, 0x02, 0x0c], // '9'
+    [0x00, 0x0c, 0x0c, 0x00, 0x0c, 0x0c, 0x00], // ':'
+    [0x00, 0x0c, 0x0c, 0x00, 0x0c, 0x04, 0x08], // ';'
+    [0x02, 0x04, 0x08, 0x10, 0x08, 0x04, 0x02], // '<'
+    [0x00, 0x00, 0x1f, 0x00, 0x1f, 0x00, 0x00], // '='
+    [0x08, 0x04, 0x02, 0x01, 0x02, 0x04, 0x08], // '>'
+    [0x0e, 0x11, 0x01, 0x02, 0x04, 0x00, 0x04], // '?'
+    [0x0e, 0x11, 0x01, 0x0d, 0x15, 0x15, 0x0e], // '@'
+    [0x0e, 0x11, 0x11, 0x1f, 0x11, 0x11, 0x11], // 'A'
+    [0x1e, 0x11, 0x11, 0x1e, 0x11, 0x11, 0x1e], // 'B'
+    [0x0e, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0e], // 'C'
+    [0x1c, 0x12, 0x11, 0x11, 0x11, 0x12, 0x1c], // 'D'
+    [0x1f, 0x10, 0x10, 0x1e, 0x10, 0x10, 0x1f], // 'E'
+    [0x1f, 0x10, 0x10, 0x1e, 0x10, 0x10, 0x10], // 'F'
+    [0x0e, 0x11, 0x10, 0x17, 0x11, 0x11, 0x0f], // 'G'
+    [0x11, 0x11, 0x11, 0x1f, 0x11, 0x11, 0x11], // 'H'
+    [0x0e, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0e], // 'I'
+    [0x07, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0c], // 'J'
+    [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11], // 'K'
+    [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1f], // 'L'
+    [0x11, 0x1b, 0x15, 0x15, 0x11, 0x11, 0x11], // 'M'
+    [0x11, 0x11, 0x19, 0x15, 0x13, 0x11, 0x11], // 'N'
+    [0x0e, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0e], // 'O'
+    [0x1e, 0x11, 0x11, 0x1e, 0x10, 0x10, 0x10], // 'P'
+    [0x0e, 0x11, 0x11, 0x11, 0x15, 0x12, 0x0d], // 'Q'
+    [0x1e, 0x11, 0x11, 0x1e, 0x14, 0x12, 0x11], // 'R'
+    [0x0f, 0x10, 0x10, 0x0e, 0x01, 0x01, 0x1e], // 'S'
+    [0x1f, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04], // 'T'
+    [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0e], // 'U'
+    [0x11, 0x11, 0x11, 0x11, 0x11, 0x0a, 0x04], // 'V'
+    [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0a], // 'W'
+    [0x11, 0x11, 0x0a, 0x04, 0x0a, 0x11, 0x11], // 'X'
+    [0x11, 0x11, 0x0a, 0x04, 0x04, 0x04, 0x04], // 'Y'
+    [0x1f, 0x01, 0x02, 0x04, 0x08, 0x10, 0x1f], // 'Z'
+    [0x0e, 0x08, 0x08, 0x08, 0x08, 0x08, 0x0e], // '['
+    [0x00, 0x10, 0x08, 0x04, 0x02, 0x01, 0x00], // '\\'
+    [0x0e, 0x02, 0x02, 0x02, 0x02, 0x02, 0x0e], // ']'
+    [0x04, 0x0a, 0x11, 0x00, 0x00, 0x00, 0x00], // '^'
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1f], // '_'
+    [0x08, 0x04, 0x02, 0x00, 0x00, 0x00, 0x00], // '`'
+    [0x00, 0x00, 0x0e, 0x01, 0x0f, 0x11, 0x0f], // 'a'
+    [0x10, 0x10, 0x16, 0x19, 0x11, 0x11, 0x1e], // 'b'
+    [0x00, 0x00, 0x0e, 0x10, 0x10, 0x11, 0x0e], // 'c'
+    [0x01, 0x01, 0x0d, 0x13, 0x11, 0x11, 0x0f], // 'd'
+    [0x00, 0x00, 0x0e, 0x11, 0x1f, 0x10, 0x0e], // 'e'
+    [0x06, 0x09, 0x08, 0x1c, 0x08, 0x08, 0x08], // 'f'
+    [0x00, 0x0f, 0x11, 0x11, 0x0f, 0x01, 0x0e], // 'g'
+    [0x10, 0x10, 0x16, 0x19, 0x11, 0x11, 0x11], // 'h'
+    [0x04, 0x00, 0x0c, 0x04, 0x04, 0x04, 0x0e], // 'i'
+    [0x02, 0x00, 0x06, 0x02, 0x02, 0x12, 0x0c], // 'j'
+    [0x10, 0x10, 0x12, 0x14, 0x18, 0x14, 0x12], // 'k'
+    [0x0c, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0e], // 'l'
+    [0x00, 0x00, 0x1a, 0x15, 0x15, 0x11, 0x11], // 'm'
+    [0x00, 0x00, 0x16, 0x19, 0x11, 0x11, 0x11], // 'n'
+    [0x00, 0x00, 0x0e, 0x11, 0x11, 0x11, 0x0e], // 'o'
+    [0x00, 0x00, 0x1e, 0x11, 0x1e, 0x10, 0x10], // 'p'
+    [0x00, 0x00, 0x0d, 0x13, 0x0f, 0x01, 0x01], // 'q'
+    [0x00, 0x00, 0x16, 0x19, 0x10, 0x10, 0x10], // 'r'
+    [0x00, 0x00, 0x0e, 0x10, 0x0e, 0x01, 0x1e], // 's'
+    [0x08, 0x08, 0x1c, 0x08, 0x08, 0x09, 0x06], // 't'
+    [0x00, 0x00, 0x11, 0x11, 0x11, 0x13, 0x0d], // 'u'
+    [0x00, 0x00, 0x11, 0x11, 0x11, 0x0a, 0x04], // 'v'
+    [0x00, 0x00, 0x11, 0x11, 0x15, 0x15, 0x0a], // 'w'
+    [0x00, 0x00, 0x11, 0x0a, 0x04, 0x0a, 0x11], // 'x'
+    [0x00, 0x00, 0x11, 0x11, 0x0f, 0x01, 0x0e], // 'y'
+    [0x00, 0x00, 0x1f, 0x02, 0x04, 0x08, 0x1f], // 'z'
+    [0x02, 0x04, 0x04, 0x08, 0x04, 0x04, 0x02], // '{'
+    [0x04, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04], // '|'
+    [0x08, 0x04, 0x04, 0x02, 0x04, 0x04, 0x08], // '}'
+    [0x00, 0x00, 0x08, 0x15, 0x02, 0x00, 0x00], // '~'
+];
+
+/// Accented letters, drawn with the glyph of their base letter.
+const ACCENTS: [(&str, char); 14] = [
+    ("ÀÁÂÃÄÅ", 'A'),
+    ("àáâãäå", 'a'),
+    ("Ç", 'C'),
+    ("ç", 'c'),
+    ("ÈÉÊË", 'E'),
+    ("èéêë", 'e'),
+    ("ÌÍÎÏ", 'I'),
+    ("ìíîï", 'i'),
+    ("Ñ", 'N'),
+    ("ñ", 'n'),
+    ("ÒÓÔÕÖ", 'O'),
+    ("òóôõö", 'o'),
+    ("ÙÚÛÜ", 'U'),
+    ("ùúûü", 'u'),
+];
+
+/// Returns the glyph used to draw `c`. Characters the font does not know are
+/// drawn as `'?'`.
+fn glyph(c: char) -> &'static [u8; GLYPH_HEIGHT as usize] {
+    let c = ACCENTS
+        .iter()
+        .find(|(accented, _)| accented.contains(c))
+        .map_or(c, |&(_, base)| base);
+
+    match c {
+        ' '..='~' => &GLYPHS[c as usize - ' ' as usize],
+        _ => &GLYPHS['?' as usize - ' ' as usize],
+    }
+}
+
+/// How a line of text is placed relative to its anchor.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Align {
+    /// The text starts at the anchor.
+    Left,
+    /// The text is centered on the anchor.
+    Center,
+    /// The text ends at the anchor.
+    Right,
+}
+
+/// The size, color and alignment of some text.
+#[derive(Clone, Copy, Debug)]
+pub struct TextStyle {
+    /// The size of a pixel of the font, in pixels of the image.
+    pub scale: u32,
+    pub color: Color,
+    pub align: Align,
+}
+
+impl TextStyle {
+    /// Returns the width of `text` drawn on a single line.
+    pub fn width(&self, text: &str) -> u32 {
+        match text.chars().count() as u32 {
+            0 => 0,
+            // There is no space after the last character.
+            n => (n * ADVANCE - 1) * self.scale,
+        }
+    }
+
+    /// Returns the height of a line of text, including the space below it.
+    pub fn line_height(&self) -> u32 {
+        LINE_HEIGHT * self.scale
+    }
+
+    /// Splits `text` into lines no wider than `max_width`.
+    ///
+    /// Lines are broken between words when possible. Words that are too long
+    /// for a line are broken between characters. Line feeds always start a
+    /// new line.
+    pub fn wrap<'a>(&self, text: &'a str, max_width: u32) -> Vec<&'a str> {
+        let max_chars = ((max_width / self.scale + 1) / ADVANCE).max(1) as usize;
+        let mut lines = Vec::new();
+
+        for paragraph in text.split('\n') {
+            let mut rest = paragraph;
+            loop {
+                if rest.chars().count() <= max_chars {
+                    lines.push(rest);
+                    break;
+                }
+
+                // The first character that does not fit, and its byte offset.
+                let (limit, c) = rest.char_indices().nth(max_chars).unwrap();
+                let space = match c {
+                    ' ' => Some(limit),
+                    _ => rest[..limit].rfind(' ').filter(|&space| space > 0),
+                };
+                let (line, next) = match space {
+                    Some(space) => (&rest[..space], &rest[space + 1..]),
+                    None => (&rest[..limit], &rest[limit..]),
+                };
+                lines.push(line.trim_end());
+                rest = next.trim_start();
+            }
+        }
+
+        lines
+    }
+
+    /// Draws `text` on a single line, anchored at (`x`, `y`), `y` being the
+    /// top of the line.
+    ///
+    /// Pixels that fall outside of `img` are skipped.
+    pub fn draw<C: Canvas>(&self, img: &mut C, text: &str, x: i32, y: i32) {
+        let width = self.width(text) as i32;
+        let mut x = match self.align {
+            Align::Left => x,
+            Align::Center => x - width / 2,
+            Align::Right => x - width,
+        };
+
+        for c in text.chars() {
+            self.draw_glyph(img, glyph(c), x, y);
+            x += (ADVANCE * self.scale) as i32;
+        }
+    }
+
+    fn draw_glyph<C: Canvas>(
+        &self,
+        img: &mut C,
+        glyph: &[u8; GLYPH_HEIGHT as usize],
+        x: i32,
+        y: i32,
+    ) {
+        let scale = self.scale as i32;
+        for (row, bits) in glyph.iter().enumerate() {
+            for col in 0..GLYPH_WIDTH {
+                if bits & (1 << (GLYPH_WIDTH - 1 - col)) == 0 {
+                    continue;
+                }
+
+                let left = x + col as i32 * scale;
+                let top = y + row as i32 * scale;
+                for py in top.max(0)..(top + scale).min(img.height() as i32) {
+                    for px in left.max(0)..(left + scale).min(img.width() as i32) {
+                        img.set_pixel(px as u32, py as u32, self.color);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! The frontends the game can be played with.
+
+use std::time::Instant;
+
+use mlx::Event;
+
+use crate::canvas::{Canvas, Framebuffer};
+
+mod minilibx;
+pub use self::minilibx::*;
+
+mod headless;
+pub use self::headless::*;
+
+mod terminal;
+pub use self::terminal::*;
+
+/// What the game needs once a frame is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frame {
+    /// The game is over.
+    Quit,
+    /// Something moves on its own, such as an animation or a message that
+    /// goes away.
+    Moving,
+    /// Nothing changes until the next event.
+    Idle,
+}
+
+/// Shows the game to the player, and receives their input.
+///
+/// Whatever the frontend, the input is given as the events of the MiniLibX,
+/// with keys identified by their X11 keysym.
+pub trait Frontend {
+    /// The canvas the game is drawn on, and the images are loaded to.
+    type Canvas: Canvas;
+
+    /// Creates a canvas of `width` by `height` pixels.
+    fn create_canvas(&self, width: u32, height: u32) -> Self::Canvas;
+
+    /// Copies `image`, decoded from the assets, to a canvas of this frontend.
+    fn load_image(&self, image: Framebuffer) -> Self::Canvas;
+
+    /// Removes the oldest event from the queue and returns it, or [`None`] if
+    /// no event is left.
+    fn poll(&mut self) -> Option<Event>;
+
+    /// Returns the size the canvas should have, such as the size of the
+    /// window.
+    fn size(&self) -> (u32, u32);
+
+    /// Returns the position of the mouse over the canvas, if it is known.
+    fn mouse_position(&self) -> Option<(i32, i32)>;
+
+    /// Shows `canvas` to the player.
+    fn present(&mut self, canvas: &Self::Canvas);
+
+    /// Waits until `deadline`, between two frames, so that the game does not
+    /// use more time than it needs.
+    fn wait(&mut self, deadline: Instant) {
+        let now = Instant::now();
+        if deadline > now {
+            std::thread::sleep(deadline - now);
+        }
+    }
+
+    /// Calls `frame` repeatedly, until it returns [`Frame::Quit`].
+    fn run(&mut self, mut frame: impl FnMut(&mut Self) -> Frame)
+    where
+        Self: Sized,
+    {
+        while frame(self) != Frame::Quit {}
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::VecDeque;
+use std::time::Instant;
+
+use mlx::Event;
+
+use crate::canvas::Framebuffer;
+use crate::frontend::{Frame, Frontend};
+
+/// Plays events given in advance, without showing anything.
+///
+/// The game is drawn in memory, on a [`Framebuffer`], so that it can be run
+/// without a display.
+pub struct Headless {
+    width: u32,
+    height: u32,
+    events: VecDeque<Event>,
+}
+
+impl Headless {
+    /// Creates a frontend with a canvas of `width` by `height` pixels, and no
+    /// events to play.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            events: VecDeque::new(),
+        }
+    }
+
+    /// Adds `event` to the events to play.
+    #[inline]
+    pub fn push(&mut self, event: Event) {
+        self.events.push_back(event);
+    }
+}
+
+impl Frontend for Headless {
+    type Canvas = Framebuffer;
+
+    #[inline]
+    fn create_canvas(&self, width: u32, height: u32) -> Framebuffer {
+        Framebuffer::new(width, height)
+    }
+
+    #[inline]
+    fn load_image(&self, image: Framebuffer) -> Framebuffer {
+        image
+    }
+
+    #[inline]
+    fn poll(&mut self) -> Option<Event> {
+        self.events.pop_front()
+    }
+
+    #[inline]
+    fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    #[inline]
+    fn mouse_position(&self) -> Option<(i32, i32)> {
+        None
+    }
+
+    /// Nothing is shown: the canvas stays in memory.
+    #[inline]
+    fn present(&mut self, _canvas: &Framebuffer) {}
+
+    /// Events are played as fast as possible, then what they started moves
+    /// at the usual rate.
+    fn wait(&mut self, deadline: Instant) {
+        if self.events.is_empty() {
+            std::thread::sleep(deadline.saturating_duration_since(Instant::now()));
+        }
+    }
+
+    /// Stops once every event has been played, and nothing moves anymore.
+    fn run(&mut self, mut frame: impl FnMut(&mut Self) -> Frame) {
+        loop {
+            match frame(self) {
+                Frame::Quit => break,
+                Frame::Idle if self.events.is_empty() => break,
+                _ => (),
+            }
+        }
+    }
+}
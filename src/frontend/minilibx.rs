@@ -0,0 +1,69 @@
+use mlx::{Blend, Event, Events, Image, Rect, Window};
+
+use crate::canvas::{Canvas, Framebuffer};
+use crate::frontend::{Frame, Frontend};
+
+/// Plays the game in a window of the MiniLibX.
+pub struct MlxFrontend<'w> {
+    window: &'w Window<'w>,
+    events: Events<'w>,
+}
+
+impl<'w> MlxFrontend<'w> {
+    /// Starts collecting the events of `window`.
+    pub fn new(window: &'w Window<'w>) -> Self {
+        Self {
+            window,
+            events: Events::new(window),
+        }
+    }
+}
+
+impl<'w> Frontend for MlxFrontend<'w> {
+    type Canvas = Image<'w>;
+
+    fn create_canvas(&self, width: u32, height: u32) -> Image<'w> {
+        self.window
+            .mlx()
+            .create_image(width, height)
+            .unwrap_or_else(|_| panic!("Failed to create an image."))
+    }
+
+    fn load_image(&self, image: Framebuffer) -> Image<'w> {
+        let (width, height) = (image.width(), image.height());
+        let mut canvas = self.create_canvas(width, height);
+        let area = Rect::new(0, 0, width, height);
+        Canvas::blit(&mut canvas, &image, area, 0, 0, Blend::Replace);
+        canvas
+    }
+
+    #[inline]
+    fn poll(&mut self) -> Option<Event> {
+        self.events.poll()
+    }
+
+    #[inline]
+    fn size(&self) -> (u32, u32) {
+        self.window.size()
+    }
+
+    #[inline]
+    fn mouse_position(&self) -> Option<(i32, i32)> {
+        Some(self.window.mouse_position())
+    }
+
+    #[inline]
+    fn present(&mut self, canvas: &Image<'w>) {
+        self.window.put_image(canvas, 0, 0);
+    }
+
+    /// Runs the loop of the MiniLibX, which is what receives the events.
+    fn run(&mut self, mut frame: impl FnMut(&mut Self) -> Frame) {
+        let mlx = self.window.mlx();
+        mlx.start_loop(|| {
+            if frame(self) == Frame::Quit {
+                mlx.stop_loop();
+            }
+        });
+    }
+}
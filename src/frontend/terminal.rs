@@ -0,0 +1,302 @@
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::io::{self, Read as _, Write as _};
+use std::time::Instant;
+
+use mlx::{Color, Event, KeyCode};
+
+use crate::canvas::{Canvas, Framebuffer};
+use crate::frontend::Frontend;
+
+/// Returns the key of the control sequence `sequence`, given without its
+/// leading `ESC [`.
+fn control_sequence(sequence: &[u8]) -> Option<KeyCode> {
+    match sequence {
+        b"A" => Some(KeyCode::UP),
+        b"B" => Some(KeyCode::DOWN),
+        b"C" => Some(KeyCode::RIGHT),
+        b"D" => Some(KeyCode::LEFT),
+        b"11~" => Some(KeyCode::F1),
+        b"12~" => Some(KeyCode::F2),
+        b"24~" => Some(KeyCode::F12),
+        _ => None,
+    }
+}
+
+/// Translates the bytes typed in a terminal into key events.
+///
+/// Control-C and Control-D close the game, like closing its window.
+pub fn key_events(mut bytes: &[u8]) -> Vec<Event> {
+    let mut events = Vec::new();
+
+    while !bytes.is_empty() {
+        let (keycode, len) = match bytes {
+            [0x03 | 0x04, ..] => {
+                events.push(Event::Destroy);
+                (None, 1)
+            }
+            [b'\r' | b'\n', ..] => (Some(KeyCode::RETURN), 1),
+            [0x7f | 0x08, ..] => (Some(KeyCode::BACKSPACE), 1),
+            [b'\t', ..] => (Some(KeyCode::TAB), 1),
+            [0x1b, b'O', b'P', ..] => (Some(KeyCode::F1), 3),
+            [0x1b, b'O', b'Q', ..] => (Some(KeyCode::F2), 3),
+            // Control sequences end with a byte in `@..=~`.
+            [0x1b, b'[', rest @ ..] => match rest.iter().position(|c| (0x40..=0x7e).contains(c)) {
+                Some(end) => (control_sequence(&rest[..=end]), end + 3),
+                None => (None, bytes.len()),
+            },
+            [0x1b, ..] => (Some(KeyCode::ESCAPE), 1),
+            _ => {
+                // The length of a UTF-8 character is given by its first byte.
+                let len = match bytes[0] {
+                    0xf0.. => 4,
+                    0xe0.. => 3,
+                    0xc0.. => 2,
+                    _ => 1,
+                };
+                let len = usize::min(len, bytes.len());
+                let c = std::str::from_utf8(&bytes[..len])
+                    .ok()
+                    .and_then(|s| s.chars().next())
+                    .filter(|c| !c.is_control());
+                (c.map(KeyCode::from_char), len)
+            }
+        };
+
+        if let Some(keycode) = keycode {
+            events.push(Event::KeyPress(keycode));
+            events.push(Event::KeyRelease(keycode));
+        }
+        bytes = &bytes[len..];
+    }
+
+    events
+}
+
+/// Returns the number of columns and rows of the terminal.
+fn terminal_size() -> (u32, u32) {
+    // Safety:
+    //  `winsize` is a plain C structure, which `ioctl` fills.
+    let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+    let ok = unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) } == 0;
+
+    if ok && size.ws_col != 0 && size.ws_row != 0 {
+        (size.ws_col as u32, size.ws_row as u32)
+    } else {
+        (80, 24)
+    }
+}
+
+/// Returns the average color of the pixels of `canvas` in `columns` and
+/// `rows`.
+fn average(
+    canvas: &Framebuffer,
+    columns: std::ops::Range<u32>,
+    rows: std::ops::Range<u32>,
+) -> Color {
+    let mut sum = [0u32; 3];
+    let mut count = 0;
+    for y in rows {
+        for x in columns.clone() {
+            let p = canvas.get_pixel(x, y).unwrap_or(Color::BLACK);
+            for (total, channel) in sum.iter_mut().zip([p.r, p.g, p.b]) {
+                *total += channel as u32;
+            }
+            count += 1;
+        }
+    }
+
+    let [r, g, b] = sum.map(|total| (total / count.max(1)) as u8);
+    Color::rgba(r, g, b, 255)
+}
+
+/// Plays the game in the terminal it is started from.
+///
+/// The canvas is scaled down to fit the terminal, and drawn with colored half
+/// blocks (`▀`), each character showing two pixels. Keys are read from the
+/// standard input.
+pub struct Terminal {
+    width: u32,
+    height: u32,
+    /// The settings of the terminal before it was switched to raw mode.
+    original: libc::termios,
+    events: VecDeque<Event>,
+    /// The size of the terminal when `cells` were drawn.
+    size: (u32, u32),
+    /// The colors of the upper and lower halves of the cells drawn last, so
+    /// that only the cells that changed are drawn again.
+    cells: Vec<(Color, Color)>,
+}
+
+impl Terminal {
+    /// Creates a frontend with a canvas of `width` by `height` pixels.
+    ///
+    /// The terminal is switched to raw mode, so that keys are received as soon
+    /// as they are typed, until the frontend is dropped. This fails if the
+    /// standard input is not a terminal.
+    pub fn new(width: u32, height: u32) -> io::Result<Self> {
+        // Safety:
+        //  `termios` is a plain C structure, which `tcgetattr` fills.
+        let mut original: libc::termios = unsafe { std::mem::zeroed() };
+        if unsafe { libc::tcgetattr(libc::STDIN_FILENO, &mut original) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let mut raw = original;
+        unsafe { libc::cfmakeraw(&mut raw) };
+        // Reading returns immediately, even when no key was typed.
+        raw.c_cc[libc::VMIN] = 0;
+        raw.c_cc[libc::VTIME] = 0;
+        if unsafe { libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &raw) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let terminal = Self {
+            width,
+            height,
+            original,
+            events: VecDeque::new(),
+            size: (0, 0),
+            cells: Vec::new(),
+        };
+
+        // Switches to the alternate screen, and hides the cursor.
+        let mut stdout = io::stdout();
+        stdout.write_all(b"\x1b[?1049h\x1b[?25l")?;
+        stdout.flush()?;
+
+        Ok(terminal)
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        let mut stdout = io::stdout();
+        let _ = stdout.write_all(b"\x1b[0m\x1b[?25h\x1b[?1049l");
+        let _ = stdout.flush();
+        unsafe { libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &self.original) };
+    }
+}
+
+impl Frontend for Terminal {
+    type Canvas = Framebuffer;
+
+    #[inline]
+    fn create_canvas(&self, width: u32, height: u32) -> Framebuffer {
+        Framebuffer::new(width, height)
+    }
+
+    #[inline]
+    fn load_image(&self, image: Framebuffer) -> Framebuffer {
+        image
+    }
+
+    fn poll(&mut self) -> Option<Event> {
+        if self.events.is_empty() {
+            let mut buffer = [0; 64];
+            if let Ok(len) = io::stdin().lock().read(&mut buffer) {
+                self.events.extend(key_events(&buffer[..len]));
+            }
+
+            // The game is only drawn again when something changed, which a
+            // resized terminal must count as.
+            if self.size != (0, 0) && terminal_size() != self.size {
+                self.size = (0, 0);
+                self.events.push_back(Event::Expose);
+            }
+        }
+
+        self.events.pop_front()
+    }
+
+    #[inline]
+    fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    #[inline]
+    fn mouse_position(&self) -> Option<(i32, i32)> {
+        None
+    }
+
+    fn present(&mut self, canvas: &Framebuffer) {
+        let mut out = String::new();
+
+        let (columns, rows) = terminal_size();
+        if (columns, rows) != self.size {
+            self.size = (columns, rows);
+            self.cells.clear();
+            out.push_str("\x1b[0m\x1b[2J");
+        }
+
+        // Each character shows two pixels, one above the other.
+        let scale = f32::min(
+            columns as f32 / canvas.width() as f32,
+            (rows * 2) as f32 / canvas.height() as f32,
+        )
+        .min(1.0);
+        let width = ((canvas.width() as f32 * scale) as u32).clamp(1, columns);
+        let height = ((canvas.height() as f32 * scale) as u32).clamp(1, rows * 2);
+        let left = (columns - width) / 2;
+
+        // The pixels of the canvas covered by the pixel `i` of a side.
+        let span = |i: u32, canvas_len: u32, len: u32| {
+            let from = i * canvas_len / len;
+            from..u32::max((i + 1) * canvas_len / len, from + 1)
+        };
+        let pixel = |x: u32, y: u32| match y < height {
+            true => average(
+                canvas,
+                span(x, canvas.width(), width),
+                span(y, canvas.height(), height),
+            ),
+            false => Color::BLACK,
+        };
+
+        let mut cells = Vec::with_capacity(self.cells.len());
+        let mut cursor = None;
+        let mut colors = None;
+        for row in 0..height.div_ceil(2) {
+            for column in 0..width {
+                let cell = (pixel(column, row * 2), pixel(column, row * 2 + 1));
+                cells.push(cell);
+                if self.cells.get(cells.len() - 1) == Some(&cell) {
+                    continue;
+                }
+
+                if cursor != Some((column, row)) {
+                    let _ = write!(out, "\x1b[{};{}H", row + 1, left + column + 1);
+                }
+                if colors != Some(cell) {
+                    let (top, bottom) = cell;
+                    let _ = write!(
+                        out,
+                        "\x1b[38;2;{};{};{}m\x1b[48;2;{};{};{}m",
+                        top.r, top.g, top.b, bottom.r, bottom.g, bottom.b
+                    );
+                }
+                out.push('▀');
+                cursor = Some((column + 1, row));
+                colors = Some(cell);
+            }
+        }
+        self.cells = cells;
+
+        if !out.is_empty() {
+            let mut stdout = io::stdout().lock();
+            let _ = stdout.write_all(out.as_bytes());
+            let _ = stdout.flush();
+        }
+    }
+
+    /// Waits until `deadline`, or until a key is typed, whichever comes first.
+    fn wait(&mut self, deadline: Instant) {
+        let timeout = deadline.saturating_duration_since(Instant::now());
+        let mut stdin = libc::pollfd {
+            fd: libc::STDIN_FILENO,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        unsafe { libc::poll(&mut stdin, 1, timeout.as_millis() as libc::c_int) };
+    }
+}
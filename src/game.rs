@@ -1,71 +1,11 @@
-/// A letter that the player can type.
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
-#[repr(u8)]
-pub enum Letter {
-    A,
-    B,
-    C,
-    D,
-    E,
-    F,
-    G,
-    H,
-    I,
-    J,
-    K,
-    L,
-    M,
-    N,
-    O,
-    P,
-    Q,
-    R,
-    S,
-    T,
-    U,
-    V,
-    W,
-    X,
-    Y,
-    Z,
-}
+use crate::language::Language;
 
-impl Letter {
-    /// Converts an ASCII character into a [`Letter`] instance.
-    ///
-    /// If the character is not an ACII letter, [`None`] is returned instead.
-    pub fn from_ascii_char(c: u8) -> Option<Self> {
-        match c.to_ascii_uppercase() {
-            b'A' => Some(Self::A),
-            b'B' => Some(Self::B),
-            b'C' => Some(Self::C),
-            b'D' => Some(Self::D),
-            b'E' => Some(Self::E),
-            b'F' => Some(Self::F),
-            b'G' => Some(Self::G),
-            b'H' => Some(Self::H),
-            b'I' => Some(Self::I),
-            b'J' => Some(Self::J),
-            b'K' => Some(Self::K),
-            b'L' => Some(Self::L),
-            b'M' => Some(Self::M),
-            b'N' => Some(Self::N),
-            b'O' => Some(Self::O),
-            b'P' => Some(Self::P),
-            b'Q' => Some(Self::Q),
-            b'R' => Some(Self::R),
-            b'S' => Some(Self::S),
-            b'T' => Some(Self::T),
-            b'U' => Some(Self::U),
-            b'V' => Some(Self::V),
-            b'W' => Some(Self::W),
-            b'X' => Some(Self::X),
-            b'Y' => Some(Self::Y),
-            b'Z' => Some(Self::Z),
-            _ => None,
-        }
-    }
-}
+/// A letter that the player can type.
+///
+/// This is the index of the letter within the alphabet of the [`Language`] being
+/// played.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Letter(pub u8);
 
 /// Describes how correct a letter is.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -79,6 +19,34 @@ pub enum Correctness {
     Correct,
 }
 
+/// A word, stored as a sequence of letters.
+pub type Word = Box<[Letter]>;
+
+/// A word that was guessed by the player, along with the correctness of each
+/// of its letters.
+pub type Guess = Box<[(Letter, Correctness)]>;
+
+/// The rules a [`Game`] is played with.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Rules {
+    /// The number of letters in each word.
+    pub word_size: usize,
+    /// The maximum number of times the player can try a word before the game ends.
+    pub max_tries: usize,
+    /// Whether revealed hints must be used in subsequent guesses.
+    pub hard: bool,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            word_size: 5,
+            max_tries: 6,
+            hard: false,
+        }
+    }
+}
+
 /// A state the game can be in.
 #[derive(Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
@@ -93,79 +61,152 @@ pub enum GameState {
     Lost,
 }
 
+/// Why the current word could not be confirmed.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Rejection {
+    /// Some letters are missing.
+    Incomplete,
+    /// The word is not in the list of allowed words.
+    Unknown,
+    /// The word does not use the revealed hints, in hard mode.
+    MissingHints,
+}
+
+/// What happened when trying to confirm the current word.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Confirmation {
+    /// The game was over, and a new one was started.
+    Restarted,
+    /// The word could not be added to the guesses.
+    Rejected(Rejection),
+    /// The word was added to the guesses.
+    Accepted,
+}
+
+/// Seeds the random number generator used to choose the winning words.
+pub fn seed_random(seed: u32) {
+    // Safety:
+    //  `srand` has no safety caveats and can be called with any seed value.
+    unsafe { libc::srand(seed) };
+}
+
 /// Stores the current state of the game.
 pub struct Game {
+    /// The rules of the game.
+    pub rules: Rules,
+    /// The language of the words.
+    pub language: &'static Language,
+
     /// A collection of valid words. Words outside of this list won't be accepted
     /// as guesses.
-    pub valid_words: Vec<[Letter; Self::WORD_SIZE]>,
+    pub valid_words: Vec<Word>,
+    /// The words that can be choosen as the winning word.
+    pub answers: Vec<Word>,
 
     /// The winning word, that the player is trying to guess.
-    pub winning_word: [Letter; Self::WORD_SIZE],
+    pub winning_word: Word,
 
     /// The current word, that the player is writing. It is not yet confiremed.
-    pub current_word: [Letter; Self::WORD_SIZE],
+    pub current_word: Box<[Letter]>,
     /// The number of character written within the `current_word` array.
     pub cursor: usize,
 
     /// The words that were previously guessed by the player.
-    pub previous_words: [[(Letter, Correctness); Self::WORD_SIZE]; Self::MAX_TRIES],
+    pub previous_words: Box<[Guess]>,
     /// The number of guesses the player tried.
     pub current_try: usize,
 
     /// The current state of the game.
     pub state: GameState,
-    /// The state of each letter
-    pub letters_state: [Option<Correctness>; 26],
+    /// The state of each letter of the alphabet.
+    pub letters_state: Box<[Option<Correctness>]>,
+
+    /// Whether the game changed since this flag was last cleared, so that it
+    /// only needs to be drawn again when it is set.
+    pub dirty: bool,
 }
 
 impl Game {
-    /// The number of letters in each word.
-    pub const WORD_SIZE: usize = 5;
-    /// The maximum number of times the player can try a word before the game ends.
-    pub const MAX_TRIES: usize = 6;
-
     /// Creates a new [`Game`] instance.
     ///
-    /// A winning word will be choosen from the given word list.
-    pub fn new(mut valid_words: Vec<[Letter; Self::WORD_SIZE]>) -> Self {
-        if valid_words.is_empty() {
-            panic!("The input word list must contain at least one value.");
+    /// A winning word will be choosen from `answers`. Both `guesses` and `answers`
+    /// are accepted as guesses.
+    pub fn new(
+        rules: Rules,
+        language: &'static Language,
+        guesses: Vec<Word>,
+        answers: Vec<Word>,
+    ) -> Self {
+        if answers.is_empty() {
+            panic!("The answer list must contain at least one value.");
         }
 
+        let mut valid_words = guesses;
+        valid_words.extend(answers.iter().cloned());
         valid_words.sort_unstable();
+        valid_words.dedup();
 
-        let index = unsafe { libc::rand() as usize % valid_words.len() };
-        let winning_word = valid_words[index];
-
-        println!(
-            "Winning Word: {:?}{:?}{:?}{:?}{:?}",
-            winning_word[0], winning_word[1], winning_word[2], winning_word[3], winning_word[4]
-        );
+        let mut game = Self {
+            rules,
+            language,
 
-        Self {
             valid_words,
-            winning_word,
+            answers,
+
+            winning_word: Box::default(),
 
-            current_word: [Letter::A; Self::WORD_SIZE],
+            current_word: vec![Letter(0); rules.word_size].into_boxed_slice(),
             cursor: 0,
 
-            previous_words: [[(Letter::A, Correctness::Incorrect); Self::WORD_SIZE];
-                Self::MAX_TRIES],
+            previous_words: vec![
+                vec![(Letter(0), Correctness::Incorrect); rules.word_size]
+                    .into_boxed_slice();
+                rules.max_tries
+            ]
+            .into_boxed_slice(),
             current_try: 0,
 
             state: GameState::Playing,
-            letters_state: [None; 26],
+            letters_state: vec![None; language.alphabet_size()].into_boxed_slice(),
+
+            dirty: true,
+        };
+
+        game.pick_winning_word();
+        game
+    }
+
+    /// Chooses a new winning word from the answer list.
+    fn pick_winning_word(&mut self) {
+        let index = unsafe { libc::rand() as usize % self.answers.len() };
+        self.winning_word = self.answers[index].clone();
+    }
+
+    /// Starts a new game with a new winning word.
+    pub fn restart(&mut self) {
+        self.pick_winning_word();
+
+        self.cursor = 0;
+        self.current_try = 0;
+        self.state = GameState::Playing;
+        self.dirty = true;
+
+        for state in self.letters_state.iter_mut() {
+            *state = None;
         }
     }
 
-    /// Types a new letter for the current game.
-    pub fn type_letter(&mut self, letter: Letter) {
-        if self.cursor == Self::WORD_SIZE || self.state != GameState::Playing {
-            return;
+    /// Types a new letter for the current game. Returns whether there was
+    /// room for it.
+    pub fn type_letter(&mut self, letter: Letter) -> bool {
+        if self.cursor == self.rules.word_size || self.state != GameState::Playing {
+            return false;
         }
 
         self.current_word[self.cursor] = letter;
         self.cursor += 1;
+        self.dirty = true;
+        true
     }
 
     /// Cancels the last typed letter.
@@ -176,76 +217,77 @@ impl Game {
 
         if self.cursor != 0 {
             self.cursor -= 1;
+            self.dirty = true;
+        }
+    }
+
+    /// Determines whether the current word uses every hint revealed by the
+    /// previous guesses, as required in hard mode.
+    fn uses_revealed_hints(&self) -> bool {
+        for previous in &self.previous_words[..self.current_try] {
+            for (i, &(letter, correctness)) in previous.iter().enumerate() {
+                match correctness {
+                    Correctness::Correct if self.current_word[i] != letter => return false,
+                    Correctness::Misplaced if !self.current_word.contains(&letter) => return false,
+                    _ => (),
+                }
+            }
         }
+
+        true
     }
 
     /// Tries to confirm the current word.
-    pub fn confirm_word(&mut self) {
+    pub fn confirm_word(&mut self) -> Confirmation {
         match self.state {
             GameState::Playing => (),
 
             // If the game isn't currently playing, reset the state of the game so we can retry.
             GameState::Won | GameState::Lost => {
-                self.current_try = 0;
-
-                let index = unsafe { libc::rand() as usize % self.valid_words.len() };
-                self.winning_word = self.valid_words[index];
-
-                println!(
-                    "Winning Word: {:?}{:?}{:?}{:?}{:?}",
-                    self.winning_word[0],
-                    self.winning_word[1],
-                    self.winning_word[2],
-                    self.winning_word[3],
-                    self.winning_word[4]
-                );
-
-                self.cursor = 0;
-                self.current_try = 0;
-                self.state = GameState::Playing;
-
-                for state in &mut self.letters_state {
-                    *state = None;
-                }
-
-                return;
+                self.restart();
+                return Confirmation::Restarted;
             }
         }
 
-        // All five letters must have been typed.
-        if self.cursor != Self::WORD_SIZE {
-            return;
+        // All letters must have been typed.
+        if self.cursor != self.rules.word_size {
+            return Confirmation::Rejected(Rejection::Incomplete);
         }
 
         // Verifies that the word is allowed.
         if self.valid_words.binary_search(&self.current_word).is_err() {
-            return;
+            return Confirmation::Rejected(Rejection::Unknown);
         }
 
-		// This array remembers whether a letter within the winning word has
+        if self.rules.hard && !self.uses_revealed_hints() {
+            return Confirmation::Rejected(Rejection::MissingHints);
+        }
+
+        // This array remembers whether a letter within the winning word has
         // already beem marked as `Misplaced`.
-        let mut seen = [false; Self::WORD_SIZE];
+        let mut seen = vec![false; self.rules.word_size];
 
         // Start by checking chich letters are correct. Every other one are
         // marked as `Incorrect`.
-        for i in 0..Self::WORD_SIZE {
+        let row = &mut self.previous_words[self.current_try];
+        for (i, s) in seen.iter_mut().enumerate() {
             let mut correctness = Correctness::Incorrect;
 
             if self.current_word[i] == self.winning_word[i] {
                 correctness = Correctness::Correct;
-				seen[i] = true;
+                *s = true;
             }
 
-            self.previous_words[self.current_try][i] = (self.current_word[i], correctness);
+            row[i] = (self.current_word[i], correctness);
         }
 
-        for (letter, correctness) in &mut self.previous_words[self.current_try] {
+        for (letter, correctness) in row.iter_mut() {
             // Only incorrect letters can be misplaced.
             if *correctness == Correctness::Correct {
                 continue;
             }
 
-            for (s, winning_letter) in seen.iter_mut().zip(self.winning_word) {
+            for (s, &winning_letter) in seen.iter_mut().zip(self.winning_word.iter()) {
                 if !*s && *letter == winning_letter {
                     *s = true;
                     *correctness = Correctness::Misplaced;
@@ -256,24 +298,22 @@ impl Game {
 
         self.cursor = 0;
 
-
-        for (letter, correctness) in self.previous_words[self.current_try] {
-            if self.letters_state[letter as usize] < Some(correctness) {
-                self.letters_state[letter as usize] = Some(correctness);
+        for &(letter, correctness) in row.iter() {
+            if self.letters_state[letter.0 as usize] < Some(correctness) {
+                self.letters_state[letter.0 as usize] = Some(correctness);
             }
         }
 
         self.current_try += 1;
+        self.dirty = true;
 
         // If the winning word is the current word, then the player won.
         if self.winning_word == self.current_word {
             self.state = GameState::Won;
-            return;
-        }
-
-        if self.current_try == Self::MAX_TRIES {
+        } else if self.current_try == self.rules.max_tries {
             self.state = GameState::Lost;
-            return;
         }
+
+        Confirmation::Accepted
     }
 }
@@ -0,0 +1,108 @@
+//! Translates the keys pressed on the physical keyboard into actions of the
+//! game.
+
+use mlx::KeyCode;
+
+use crate::keyboard::KeyAction;
+use crate::language::Language;
+
+/// The letters formed by dead keys: each letter of the second string is the
+/// letter at the same position in the first one, with the accent of the dead
+/// key.
+const COMPOSE: &[(KeyCode, &str, &str)] = &[
+    (KeyCode::DEAD_GRAVE, "AEIOU", "ÀÈÌÒÙ"),
+    (KeyCode::DEAD_ACUTE, "ACEINOSUYZ", "ÁĆÉÍŃÓŚÚÝŹ"),
+    (KeyCode::DEAD_CIRCUMFLEX, "AEIOU", "ÂÊÎÔÛ"),
+    (KeyCode::DEAD_TILDE, "ANO", "ÃÑÕ"),
+    (KeyCode::DEAD_DIAERESIS, "AEIOUY", "ÄËÏÖÜŸ"),
+    (KeyCode::DEAD_ABOVE_RING, "AU", "ÅŮ"),
+    (KeyCode::DEAD_CARON, "CDENRSTZ", "ČĎĚŇŘŠŤŽ"),
+    (KeyCode::DEAD_CEDILLA, "CS", "ÇŞ"),
+];
+
+/// Adds the accent of the dead key `dead` to `c`. Letters that cannot take the
+/// accent are left unchanged.
+fn compose(dead: KeyCode, c: char) -> char {
+    let mut upper = c.to_uppercase();
+    let base = match (upper.next(), upper.next()) {
+        (Some(u), None) => u,
+        _ => return c,
+    };
+
+    COMPOSE
+        .iter()
+        .filter(|&&(key, _, _)| key == dead)
+        .find_map(|(_, bases, composed)| {
+            let index = bases.chars().position(|b| b == base)?;
+            composed.chars().nth(index)
+        })
+        .unwrap_or(c)
+}
+
+/// Turns key presses into [`KeyAction`]s.
+///
+/// Any keysym that stands for a character is accepted, whatever the case or
+/// the keyboard layout, and dead keys add their accent to the next letter.
+#[derive(Debug, Default)]
+pub struct TextInput {
+    /// The dead key waiting for a letter.
+    dead: Option<KeyCode>,
+    /// Whether a Shift key is held.
+    shift: bool,
+}
+
+impl TextInput {
+    /// Handles the press of `keycode`, returning the resulting action for a
+    /// game in `language`.
+    pub fn key_press(&mut self, keycode: KeyCode, language: &Language) -> Option<KeyAction> {
+        match keycode {
+            KeyCode::SHIFT_L | KeyCode::SHIFT_R => {
+                self.shift = true;
+                return None;
+            }
+            // Shift may be needed to reach the letter following a dead key.
+            _ if keycode.is_modifier() => return None,
+            _ if keycode.is_dead() => {
+                self.dead = Some(keycode);
+                return None;
+            }
+            KeyCode::RETURN | KeyCode::KP_ENTER => {
+                self.dead = None;
+                return Some(KeyAction::Enter);
+            }
+            KeyCode::BACKSPACE => {
+                self.dead = None;
+                return Some(KeyAction::Backspace);
+            }
+            _ => (),
+        }
+
+        let dead = self.dead.take();
+        let c = keycode.to_char()?;
+        let c = match dead {
+            Some(dead) => compose(dead, c),
+            None => c,
+        };
+
+        language.letter(c).map(KeyAction::Letter)
+    }
+
+    /// Handles the release of `keycode`.
+    pub fn key_release(&mut self, keycode: KeyCode) {
+        if keycode == KeyCode::SHIFT_L || keycode == KeyCode::SHIFT_R {
+            self.shift = false;
+        }
+    }
+
+    /// Returns whether `keycode` toggles the help screen.
+    ///
+    /// Keysyms are received without Shift applied, so Shift and the slash key
+    /// is also recognized as `?`, as on US keyboards.
+    pub fn is_help(&self, keycode: KeyCode) -> bool {
+        match keycode.to_char() {
+            Some('?') => true,
+            Some('/') => self.shift,
+            _ => keycode == KeyCode::F1,
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use crate::game::Letter;
+use crate::language::Language;
+
+/// What pressing a key of the on-screen keyboard does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyAction {
+    /// Types a letter.
+    Letter(Letter),
+    /// Confirms the current word.
+    Enter,
+    /// Erases the last letter.
+    Backspace,
+}
+
+/// A row of an on-screen keyboard layout.
+#[derive(Debug)]
+pub struct Row {
+    /// The letters of the row, from left to right.
+    pub letters: &'static str,
+    /// How far the row is shifted to the right of the center of the keyboard,
+    /// in pixels.
+    pub offset: i32,
+}
+
+/// The arrangement of the letters of the on-screen keyboard.
+#[derive(Debug)]
+pub struct Layout {
+    /// The name of the layout, as used in the configuration file.
+    pub name: &'static str,
+    /// The rows of the layout, from top to bottom.
+    pub rows: &'static [Row],
+}
+
+const fn row(letters: &'static str) -> Row {
+    Row { letters, offset: 0 }
+}
+
+pub const QWERTY: Layout = Layout {
+    name: "qwerty",
+    rows: &[row("QWERTYUIOP"), row("ASDFGHJKL"), row("ZXCVBNM")],
+};
+
+/// The Spanish variant of QWERTY, with `Ñ` next to `L`.
+pub const QWERTY_ES: Layout = Layout {
+    name: "qwerty-es",
+    rows: &[row("QWERTYUIOP"), row("ASDFGHJKLÑ"), row("ZXCVBNM")],
+};
+
+pub const AZERTY: Layout = Layout {
+    name: "azerty",
+    rows: &[row("AZERTYUIOP"), row("QSDFGHJKLM"), row("WXCVBN")],
+};
+
+pub const QWERTZ: Layout = Layout {
+    name: "qwertz",
+    rows: &[row("QWERTZUIOPÜ"), row("ASDFGHJKLÖÄ"), row("YXCVBNMß")],
+};
+
+/// Dvorak. Punctuation keys are left out, so rows are shifted to keep the
+/// letters in place.
+pub const DVORAK: Layout = Layout {
+    name: "dvorak",
+    rows: &[
+        Row {
+            letters: "PYFGCRL",
+            offset: 63,
+        },
+        row("AOEUIDHTNS"),
+        Row {
+            letters: "QJKXBMWVZ",
+            offset: 21,
+        },
+    ],
+};
+
+pub const ALPHABETICAL: Layout = Layout {
+    name: "alphabetical",
+    rows: &[row("ABCDEFGHI"), row("JKLMNOPQR"), row("STUVWXYZ")],
+};
+
+/// Every available layout.
+pub const LAYOUTS: [&Layout; 6] = [
+    &QWERTY,
+    &QWERTY_ES,
+    &AZERTY,
+    &QWERTZ,
+    &DVORAK,
+    &ALPHABETICAL,
+];
+
+/// A row of keys, ready to be displayed.
+pub struct KeyRow {
+    pub letters: Vec<Letter>,
+    pub offset: i32,
+}
+
+impl Layout {
+    /// Finds the layout named `name`.
+    pub fn from_name(name: &str) -> Option<&'static Layout> {
+        LAYOUTS.into_iter().find(|layout| layout.name == name)
+    }
+
+    /// Returns the keys of this layout for the letters of `language`.
+    ///
+    /// Characters of the layout that are not letters of the alphabet are left
+    /// out, and letters of the alphabet missing from the layout are added to
+    /// the shortest rows, so that every letter can be typed.
+    pub fn rows(&self, language: &Language) -> Vec<KeyRow> {
+        let mut rows: Vec<KeyRow> = self
+            .rows
+            .iter()
+            .map(|row| KeyRow {
+                letters: row
+                    .letters
+                    .chars()
+                    .filter_map(|c| language.letter(c).filter(|&l| language.char(l) == c))
+                    .collect(),
+                offset: row.offset,
+            })
+            .collect();
+
+        for index in 0..language.alphabet_size() {
+            let letter = Letter(index as u8);
+            if rows.iter().any(|row| row.letters.contains(&letter)) {
+                continue;
+            }
+
+            if let Some(shortest) = rows.iter_mut().min_by_key(|row| row.letters.len()) {
+                shortest.letters.push(letter);
+            }
+        }
+
+        rows
+    }
+}
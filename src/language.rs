@@ -0,0 +1,158 @@
+use crate::game::Letter;
+use crate::keyboard::{self, Layout};
+
+/// The definition of a language the game can be played in.
+#[derive(Debug)]
+pub struct Language {
+    /// The code of the language, as used in the configuration file.
+    pub code: &'static str,
+    /// The name of the language.
+    pub name: &'static str,
+    /// The uppercase letters of the alphabet. The index of a letter in this
+    /// list is the value of the corresponding [`Letter`].
+    pub letters: &'static [char],
+    /// Characters that are accepted in place of a letter of the alphabet.
+    pub folding: &'static [(char, char)],
+    /// Ligatures that word lists may use in place of several letters of the
+    /// alphabet.
+    pub ligatures: &'static [(char, &'static str)],
+    /// The default layout of the on-screen keyboard.
+    pub layout: &'static Layout,
+}
+
+const LATIN: [char; 26] = [
+    'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S',
+    'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
+];
+
+/// English.
+pub const ENGLISH: Language = Language {
+    code: "en",
+    name: "English",
+    letters: &LATIN,
+    folding: &[],
+    ligatures: &[],
+    layout: &keyboard::QWERTY,
+};
+
+/// French. Accented letters are equivalent to their base letter, and `Œ` and
+/// `Æ` to the two letters they join.
+pub const FRENCH: Language = Language {
+    code: "fr",
+    name: "Français",
+    letters: &LATIN,
+    folding: &[
+        ('À', 'A'),
+        ('Â', 'A'),
+        ('Ä', 'A'),
+        ('Ç', 'C'),
+        ('É', 'E'),
+        ('È', 'E'),
+        ('Ê', 'E'),
+        ('Ë', 'E'),
+        ('Î', 'I'),
+        ('Ï', 'I'),
+        ('Ô', 'O'),
+        ('Ö', 'O'),
+        ('Ù', 'U'),
+        ('Û', 'U'),
+        ('Ü', 'U'),
+        ('Ÿ', 'Y'),
+    ],
+    ligatures: &[('Œ', "OE"), ('œ', "OE"), ('Æ', "AE"), ('æ', "AE")],
+    layout: &keyboard::AZERTY,
+};
+
+/// Spanish. `Ñ` is a letter of its own, but vowels with an accent are
+/// equivalent to their base letter.
+pub const SPANISH: Language = Language {
+    code: "es",
+    name: "Español",
+    letters: &[
+        'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R',
+        'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'Ñ',
+    ],
+    folding: &[
+        ('Á', 'A'),
+        ('É', 'E'),
+        ('Í', 'I'),
+        ('Ó', 'O'),
+        ('Ú', 'U'),
+        ('Ü', 'U'),
+    ],
+    ligatures: &[],
+    layout: &keyboard::QWERTY_ES,
+};
+
+/// German. Umlauts and `ß` are letters of their own.
+pub const GERMAN: Language = Language {
+    code: "de",
+    name: "Deutsch",
+    letters: &[
+        'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R',
+        'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', 'Ä', 'Ö', 'Ü', 'ß',
+    ],
+    folding: &[('ẞ', 'ß')],
+    ligatures: &[],
+    layout: &keyboard::QWERTZ,
+};
+
+/// Every language the game can be played in.
+pub const LANGUAGES: [&Language; 4] = [&ENGLISH, &FRENCH, &SPANISH, &GERMAN];
+
+impl Language {
+    /// Finds the language with the provided code.
+    pub fn from_code(code: &str) -> Option<&'static Language> {
+        LANGUAGES.into_iter().find(|lang| lang.code == code)
+    }
+
+    /// Returns the number of letters in the alphabet.
+    #[inline]
+    pub fn alphabet_size(&self) -> usize {
+        self.letters.len()
+    }
+
+    /// Converts a character into a [`Letter`] of this language.
+    ///
+    /// The character may be lowercase or, when allowed by the language,
+    /// accented. If the character is not part of the alphabet, [`None`] is
+    /// returned instead.
+    pub fn letter(&self, c: char) -> Option<Letter> {
+        let find = |c: char| {
+            let c = self
+                .folding
+                .iter()
+                .find(|&&(from, _)| from == c)
+                .map_or(c, |&(_, to)| to);
+            self.letters.iter().position(|&l| l == c)
+        };
+
+        // Some letters, like `ß`, have no single-character uppercase form.
+        let mut upper = c.to_uppercase();
+        let index = match (upper.next(), upper.next()) {
+            (Some(u), None) => find(u).or_else(|| find(c)),
+            _ => find(c),
+        }?;
+
+        Some(Letter(index as u8))
+    }
+
+    /// Returns the characters of `word`, with its ligatures replaced by the
+    /// letters they join.
+    pub fn expand<'a>(&'a self, word: &'a str) -> impl Iterator<Item = char> + 'a {
+        word.chars().flat_map(|c| {
+            let ligature = self.ligatures.iter().find(|&&(from, _)| from == c);
+            let (single, letters) = match ligature {
+                Some(&(_, letters)) => (None, letters),
+                None => (Some(c), ""),
+            };
+            single.into_iter().chain(letters.chars())
+        })
+    }
+
+    /// Returns the character of a [`Letter`] of this language.
+    #[inline]
+    pub fn char(&self, letter: Letter) -> char {
+        self.letters[letter.0 as usize]
+    }
+}
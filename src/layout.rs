@@ -0,0 +1,155 @@
+//! Computes where the elements of the game are drawn, for any window size.
+//!
+//! Every length is first defined at a scale of 1, for which the sprites were
+//! drawn, then scaled to fit the window.
+
+use crate::game::Rules;
+
+/// The size of the tiles of the grid.
+const TILE_SIZE: u32 = 64;
+/// The space between two tiles of the grid.
+const TILE_GAP: u32 = 10;
+/// The space above the grid.
+const GRID_MARGIN: u32 = 30;
+/// The minimum space on the sides of the grid.
+const GRID_SIDE_MARGIN: u32 = 55;
+/// The space between the grid and the area below it.
+const BOTTOM_MARGIN: u32 = 30;
+/// The height of the area below the grid, for the keyboard or the final
+/// screen.
+const BOTTOM_HEIGHT: u32 = 146;
+/// The width taken by the keyboard.
+const KEYBOARD_WIDTH: u32 = 470;
+/// The size of the keys of the keyboard.
+const KEY_SIZE: u32 = 32;
+/// The space between two keys of the keyboard.
+const KEY_GAP: u32 = 10;
+/// The width of the Enter and Backspace keys of the keyboard.
+const WIDE_KEY_WIDTH: u32 = 53;
+
+/// Computes the size of the content of the window at a scale of 1.
+fn content_size(rules: &Rules) -> (u32, u32) {
+    let grid_width = rules.word_size as u32 * (TILE_SIZE + TILE_GAP) - TILE_GAP;
+    let grid_height = rules.max_tries as u32 * (TILE_SIZE + TILE_GAP) - TILE_GAP;
+
+    (
+        u32::max(grid_width + 2 * GRID_SIDE_MARGIN, KEYBOARD_WIDTH),
+        GRID_MARGIN + grid_height + BOTTOM_MARGIN + BOTTOM_HEIGHT,
+    )
+}
+
+/// Computes the size of the window required to display a game with the
+/// provided rules, at the provided scale.
+pub fn window_size(rules: &Rules, scale: f32) -> (u32, u32) {
+    let (width, height) = content_size(rules);
+    (
+        (width as f32 * scale).round() as u32,
+        (height as f32 * scale).round() as u32,
+    )
+}
+
+/// The position and size of the elements of the game in a window.
+#[derive(Clone, Copy, Debug)]
+pub struct Geometry {
+    /// The scale of the content, relative to the size of the sprites.
+    pub scale: f32,
+    /// The height of the window.
+    pub height: u32,
+
+    /// The size of the tiles of the grid.
+    pub tile_size: u32,
+    /// The space between two tiles of the grid.
+    pub tile_gap: u32,
+    /// The position of the top-left tile of the grid.
+    pub grid_x: u32,
+    pub grid_y: u32,
+
+    /// The position of the area below the grid.
+    pub bottom_y: u32,
+
+    /// The position and width of the keyboard.
+    pub keyboard_x: u32,
+    pub keyboard_width: u32,
+    /// The size of the letter keys of the keyboard.
+    pub key_size: u32,
+    /// The space between two keys of the keyboard.
+    pub key_gap: u32,
+    /// The width of the Enter and Backspace keys of the keyboard.
+    pub wide_key_width: u32,
+}
+
+impl Geometry {
+    /// Computes the geometry of a game with the provided rules, in a window of
+    /// `width` by `height` pixels.
+    ///
+    /// The content is scaled as much as possible while keeping its
+    /// proportions, and centered in the window.
+    pub fn new(width: u32, height: u32, rules: &Rules) -> Self {
+        let (content_width, content_height) = content_size(rules);
+        let mut scale = f32::min(
+            width as f32 / content_width as f32,
+            height as f32 / content_height as f32,
+        );
+
+        // Rounding every length may make the content slightly too big.
+        loop {
+            match Self::with_scale(width, height, rules, scale) {
+                Some(geometry) => return geometry,
+                None => scale *= 0.99,
+            }
+        }
+    }
+
+    /// Computes the geometry of a game at the provided scale, if its content
+    /// fits in the window.
+    fn with_scale(width: u32, height: u32, rules: &Rules, scale: f32) -> Option<Self> {
+        let px = |length: u32| u32::max((length as f32 * scale).round() as u32, 1);
+
+        let tile_size = px(TILE_SIZE);
+        let tile_gap = px(TILE_GAP);
+        let grid_width = rules.word_size as u32 * (tile_size + tile_gap) - tile_gap;
+        let grid_height = rules.max_tries as u32 * (tile_size + tile_gap) - tile_gap;
+        let keyboard_width = px(KEYBOARD_WIDTH);
+
+        // The keyboard has three rows, which may not fit at small scales.
+        let bottom_height = u32::max(px(BOTTOM_HEIGHT), 3 * (px(KEY_SIZE) + px(KEY_GAP)));
+        let content_height = px(GRID_MARGIN) + grid_height + px(BOTTOM_MARGIN) + bottom_height;
+        if u32::max(grid_width, keyboard_width) > width || content_height > height {
+            return None;
+        }
+
+        let grid_y = (height - content_height) / 2 + px(GRID_MARGIN);
+
+        Some(Self {
+            scale,
+            height,
+
+            tile_size,
+            tile_gap,
+            grid_x: (width - grid_width) / 2,
+            grid_y,
+
+            bottom_y: grid_y + grid_height + px(BOTTOM_MARGIN),
+
+            keyboard_x: (width - keyboard_width) / 2,
+            keyboard_width,
+            key_size: px(KEY_SIZE),
+            key_gap: px(KEY_GAP),
+            wide_key_width: px(WIDE_KEY_WIDTH),
+        })
+    }
+
+    /// Scales a length given at a scale of 1. The result is at least 1.
+    pub fn px(&self, length: u32) -> u32 {
+        u32::max((length as f32 * self.scale).round() as u32, 1)
+    }
+
+    /// Returns the position of the tile at `row` and `col` of the grid.
+    pub fn tile(&self, row: usize, col: usize) -> (u32, u32) {
+        let step = self.tile_size + self.tile_gap;
+        (
+            self.grid_x + col as u32 * step,
+            self.grid_y + row as u32 * step,
+        )
+    }
+}
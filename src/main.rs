@@ -1,7 +1,4 @@
 use mlx::*;
-use std::cell::RefCell;
-use std::ffi::CStr;
-use std::rc::Rc;
 
 mod display;
 use display::*;
@@ -14,64 +11,214 @@ mod custom_panic;
 mod contents;
 use contents::*;
 
-#[inline]
-fn cstr(s: &str) -> &CStr {
-    CStr::from_bytes_with_nul(s.as_bytes()).unwrap()
+mod cli;
+
+mod config;
+use config::*;
+
+mod resources;
+use resources::*;
+
+mod dict;
+mod dict_tool;
+
+mod language;
+use language::*;
+
+mod keyboard;
+use keyboard::*;
+
+mod input;
+use input::*;
+
+mod animation;
+use animation::*;
+
+mod layout;
+use layout::*;
+
+mod theme;
+use theme::*;
+
+mod font;
+
+mod menu;
+use menu::*;
+
+mod stats;
+use stats::*;
+
+mod canvas;
+use canvas::*;
+
+mod decode;
+
+mod zlib;
+
+mod export;
+
+mod frontend;
+use frontend::*;
+
+mod app;
+use app::*;
+
+#[cfg(feature = "embed")]
+mod embedded;
+
+#[cfg(test)]
+mod snapshots;
+
+#[cfg(test)]
+mod bench;
+
+/// Returns the seed of the random number generator for `config`.
+fn seed(config: &Config) -> u32 {
+    config.seed.unwrap_or_else(|| {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        match config.mode {
+            Mode::Random => now as u32,
+            // Everyone playing on the same day gets the same words.
+            Mode::Daily => (now / (24 * 60 * 60)) as u32,
+        }
+    })
 }
 
-const WIDTH: u32 = 470;
-const HEIGHT: u32 = 640;
+/// Plays `game` with `frontend` until the player quits, then saves the result
+/// if it was requested with `--render-result`.
+///
+/// Fails if the images cannot be loaded from `assets`, or if the result cannot
+/// be saved.
+fn play<F: Frontend>(
+    frontend: &mut F,
+    config: &Config,
+    game: Game,
+    assets: &AssetSource,
+) -> Result<(), String> {
+    // An unsupported file is reported before the game is played.
+    if let Some(path) = &config.render_result {
+        export::Format::of(path).map_err(|err| err.to_string())?;
+    }
 
-fn create_dict() -> Vec<[Letter; 5]> {
-    let mut vec = Vec::<u8>::new();
+    let (width, height) = frontend.size();
+    let mut view = View::new(config, width, height);
 
-    unsafe {
-        let mut count: libc::ssize_t;
+    // The help is shown on the first launch only. Without a screen, it would
+    // only get in the way of the keys.
+    if config.show_help && config.path.is_some() && config.ui != Ui::Headless {
+        view.menu.open(Screen::Help);
+        view.menu.config.show_help = false;
+        if let Err(err) = view.menu.config.save(&["show-help"]) {
+            eprintln!("warning: {}", err);
+        }
+    }
 
-        let fd = libc::open(b"words.txt\0".as_ptr() as _, libc::O_RDONLY);
+    let sprites = Images::load(frontend, assets).map_err(|err| err.to_string())?;
+    let mut app = App::new(frontend, game, view, sprites);
+    frontend.run(|frontend| app.frame(frontend));
 
-        if fd < 0 {
-            panic!("Failed to open 'words.txt'.");
+    match &config.render_result {
+        Some(_) if app.game().state == GameState::Playing => {
+            Err("the game is not finished once the keys are played".into())
         }
+        Some(path) => app.save(path).map_err(|err| err.to_string()),
+        None => Ok(()),
+    }
+}
 
-        loop {
-            vec.reserve(2048);
-            count = libc::read(
-                fd,
-                vec.as_mut_ptr().add(vec.len()) as _,
-                vec.capacity() - vec.len(),
-            );
+/// Shrinks a window `size` that would not fit on a `screen` of the given size,
+/// keeping its proportions.
+fn fit_to_screen(size: (u32, u32), screen: (u32, u32)) -> (u32, u32) {
+    // Leaves room for the panels and the decorations of the window manager.
+    let max = (screen.0 * 9 / 10, screen.1 * 9 / 10);
+    if size.0 <= max.0 && size.1 <= max.1 {
+        return size;
+    }
 
-            if count < 0 {
-                panic!("Failed to read from 'words.txt'");
-            }
+    let ratio = f64::min(max.0 as f64 / size.0 as f64, max.1 as f64 / size.1 as f64);
+    let shrink = |n: u32| ((n as f64 * ratio) as u32).max(MIN_WINDOW_SIZE);
+    (shrink(size.0), shrink(size.1))
+}
 
-            if count == 0 {
-                break;
+/// Unwraps the result of loading a word list, exiting the process on error.
+fn check_dict(result: Result<dict::Dictionary, dict::DictError>) -> Vec<Word> {
+    match result {
+        Ok(dict) => {
+            if dict.report.skipped_any() {
+                eprintln!("warning: loaded {}", dict.report);
             }
+            dict.words
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            std::process::exit(1);
+        }
+    }
+}
 
-            vec.set_len(vec.len() + count as usize);
+/// Builds the configuration of the game from the configuration file and the
+/// command-line arguments.
+///
+/// Exits the process if the help message or the version were requested, or
+/// if an argument is invalid.
+fn load_config() -> Config {
+    let args = match cli::parse(std::env::args_os().skip(1)) {
+        Ok(cli::Action::Play(args)) => args,
+        Ok(cli::Action::Help) => {
+            cli::print_help();
+            std::process::exit(0);
+        }
+        Ok(cli::Action::Dict(command, language)) => {
+            std::process::exit(dict_tool::run(command, language))
+        }
+        Ok(cli::Action::Version) => {
+            println!("wordle {}", env!("CARGO_PKG_VERSION"));
+            std::process::exit(0);
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            eprintln!("Try 'wordle --help' for more information.");
+            std::process::exit(2);
         }
     };
 
-    vec.split(|c| *c == b'\n')
-        .enumerate()
-        .map(|(i, s)| {
-            if let [a, b, c, d, e] = *s {
-                if let (Some(a), Some(b), Some(c), Some(d), Some(e)) = (
-                    Letter::from_ascii_char(a),
-                    Letter::from_ascii_char(b),
-                    Letter::from_ascii_char(c),
-                    Letter::from_ascii_char(d),
-                    Letter::from_ascii_char(e),
-                ) {
-                    return [a, b, c, d, e];
-                }
+    let mut config = Config::default();
+
+    if !args.no_config {
+        config.path = args.config.clone().or_else(Config::default_path);
+
+        // A missing configuration file is only an error when it was requested
+        // explicitly.
+        let path = match args.config {
+            Some(path) => Some(path),
+            None => Config::default_path().filter(|path| path.exists()),
+        };
+
+        if let Some(path) = path {
+            if let Err(err) = config.load_file(&path) {
+                eprintln!("error: {}", err);
+                std::process::exit(2);
             }
+        }
+    }
+
+    for (key, value) in &args.settings {
+        if let Err(err) = config.set(key, value) {
+            eprintln!("error: --{}: {}", key, err);
+            std::process::exit(2);
+        }
+    }
+
+    // The result is rendered once the animations would be over.
+    if let Some(path) = args.render_result {
+        config.render_result = Some(path);
+        config.ui = Ui::Headless;
+        config.reduced_motion = true;
+    }
 
-            panic!("Wrong word on line {}: '{}'", i + 1, s.escape_ascii());
-        })
-        .collect()
+    config
 }
 
 fn main() {
@@ -79,69 +226,106 @@ fn main() {
     // error.
     custom_panic::set_custom_panic_hook();
 
-    // Safety:
-    //  `srand` has no safety caveats and can be called with any seed value.
-    //  `time` can be called with `NULL`.
-    unsafe { libc::srand(libc::time(std::ptr::null_mut()) as u32) };
+    let config = load_config();
 
-    let game = Rc::new(RefCell::new(Game::new(create_dict())));
+    seed_random(seed(&config));
 
-    // Initialize MiniLibX and load the images.
-    let mlx = Mlx::init().unwrap_or_else(|_| panic!("Failed to initialize the MiniLibX."));
-
-    // This image is used to draw on the whole screen.
-    let win = unsafe { mlx.create_window(WIDTH, HEIGHT, cstr("Wordle\0")) }
-        .unwrap_or_else(|_| panic!("Failed to create a window."));
+    // Files found on disk take precedence over the embedded ones.
+    let locator = Locator::new();
+    let word_size = config.rules.word_size;
+    let language = config.language;
+    let guesses = check_dict(match &config.words {
+        Some(path) => dict::load(path, word_size, language),
+        None => match locator.find(config.default_words_name()) {
+            Ok(path) => dict::load(&path, word_size, language),
+            // Only the English list is embedded.
+            #[cfg(feature = "embed")]
+            Err(_) if language.code == "en" => {
+                dict::parse(embedded::WORDS, "embedded words.txt", word_size, language)
+            }
+            Err(err) => {
+                eprintln!("error: {}", err);
+                if language.code != ENGLISH.code {
+                    eprintln!(
+                        "No {} word list is shipped with the game: give one with '--words <PATH>'.",
+                        language.name
+                    );
+                }
+                std::process::exit(1);
+            }
+        },
+    });
+    let answers = match &config.answers {
+        Some(path) => check_dict(dict::load(path, word_size, language)),
+        None => guesses.clone(),
+    };
+    let game = Game::new(config.rules, language, guesses, answers);
 
-    let _h = unsafe {
-        win.hook(|Destroy| {
-            win.mlx().stop_loop();
-        })
+    let assets = match &config.assets {
+        Some(path) => AssetSource::Dir(path.clone()),
+        None => match locator.find("assets") {
+            Ok(path) => AssetSource::Dir(path),
+            #[cfg(feature = "embed")]
+            Err(_) => AssetSource::Embedded,
+            #[cfg(not(feature = "embed"))]
+            Err(err) => {
+                eprintln!("error: {}", err);
+                std::process::exit(1);
+            }
+        },
     };
 
-    let _h = unsafe {
-        win.hook(|KeyPress(keycode)| {
-            let mut game = game.borrow_mut();
-
-            match keycode {
-                KeyCode::A => game.type_letter(Letter::A),
-                KeyCode::B => game.type_letter(Letter::B),
-                KeyCode::C => game.type_letter(Letter::C),
-                KeyCode::D => game.type_letter(Letter::D),
-                KeyCode::E => game.type_letter(Letter::E),
-                KeyCode::F => game.type_letter(Letter::F),
-                KeyCode::G => game.type_letter(Letter::G),
-                KeyCode::H => game.type_letter(Letter::H),
-                KeyCode::I => game.type_letter(Letter::I),
-                KeyCode::J => game.type_letter(Letter::J),
-                KeyCode::K => game.type_letter(Letter::K),
-                KeyCode::L => game.type_letter(Letter::L),
-                KeyCode::M => game.type_letter(Letter::M),
-                KeyCode::N => game.type_letter(Letter::N),
-                KeyCode::O => game.type_letter(Letter::O),
-                KeyCode::P => game.type_letter(Letter::P),
-                KeyCode::Q => game.type_letter(Letter::Q),
-                KeyCode::R => game.type_letter(Letter::R),
-                KeyCode::S => game.type_letter(Letter::S),
-                KeyCode::T => game.type_letter(Letter::T),
-                KeyCode::U => game.type_letter(Letter::U),
-                KeyCode::V => game.type_letter(Letter::V),
-                KeyCode::W => game.type_letter(Letter::W),
-                KeyCode::X => game.type_letter(Letter::X),
-                KeyCode::Y => game.type_letter(Letter::Y),
-                KeyCode::Z => game.type_letter(Letter::Z),
-                KeyCode::BACKSPACE => game.cancel_letter(),
-                KeyCode::RETURN => game.confirm_word(),
-                KeyCode::ESCAPE => win.mlx().stop_loop(),
-                _ => (),
+    // The frontend is dropped before exiting, so that the terminal is restored.
+    let result = match config.ui {
+        Ui::Mlx => {
+            let mlx = Mlx::init().unwrap_or_else(|_| panic!("Failed to initialize the MiniLibX."));
+
+            let screen = mlx.screen_size();
+            let (width, height) = config.window_size.unwrap_or_else(|| {
+                fit_to_screen(window_size(&config.rules, config.scale()), screen)
+            });
+
+            let win = mlx
+                .create_window(width, height, c"Wordle")
+                .unwrap_or_else(|_| panic!("Failed to create a window."));
+            win.set_position(
+                (screen.0 as i32 - width as i32).max(0) / 2,
+                (screen.1 as i32 - height as i32).max(0) / 2,
+            );
+
+            let mut frontend = MlxFrontend::new(&win);
+            play(&mut frontend, &config, game, &assets)
+        }
+        Ui::Terminal => {
+            let (width, height) = config
+                .window_size
+                .unwrap_or_else(|| window_size(&config.rules, config.scale()));
+            let mut terminal = Terminal::new(width, height).unwrap_or_else(|err| {
+                eprintln!("error: cannot use the terminal: {}", err);
+                std::process::exit(1);
+            });
+            play(&mut terminal, &config, game, &assets)
+        }
+        Ui::Headless => {
+            let (width, height) = config
+                .window_size
+                .unwrap_or_else(|| window_size(&config.rules, config.scale()));
+            let mut headless = Headless::new(width, height);
+
+            let mut keys = Vec::new();
+            if let Err(err) = std::io::Read::read_to_end(&mut std::io::stdin(), &mut keys) {
+                eprintln!("error: cannot read the standard input: {}", err);
+                std::process::exit(1);
             }
-        })
+            for event in key_events(&keys) {
+                headless.push(event);
+            }
+            play(&mut headless, &config, game, &assets)
+        }
     };
 
-    let canvas = unsafe { win.mlx().create_image(WIDTH, HEIGHT).unwrap() };
-    let images = unsafe { Images::load(win.mlx()) };
-    mlx.start_loop(|| {
-        draw(&game.borrow(), &canvas, &images);
-        win.put_image(&canvas, 0, 0);
-    });
+    if let Err(err) = result {
+        eprintln!("error: {}", err);
+        std::process::exit(1);
+    }
 }
@@ -0,0 +1,250 @@
+//! The screens shown around the game, and how to navigate between them.
+
+use mlx::KeyCode;
+
+use crate::config::{Config, Mode};
+use crate::keyboard::LAYOUTS;
+use crate::theme::THEMES;
+
+/// A screen of the game.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Screen {
+    /// The grid and the keyboard, no screen being shown over them.
+    Playing,
+    /// The main menu.
+    Menu,
+    /// The settings that can be changed while playing.
+    Settings,
+    /// The statistics of the games played so far.
+    Stats,
+    /// How to play.
+    Help,
+}
+
+/// A setting that can be changed from the settings screen.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Setting {
+    Mode,
+    Hard,
+    Theme,
+    Layout,
+    ReducedMotion,
+}
+
+/// A row of a screen, which does something when activated.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Entry {
+    /// Goes back to the game.
+    Play,
+    /// Opens another screen.
+    Open(Screen),
+    /// Quits the game.
+    Quit,
+    /// Changes a setting.
+    Setting(Setting),
+    /// Goes back to the main menu.
+    Back,
+}
+
+/// Something the menu asks the rest of the game to do.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Command {
+    /// Close the window.
+    Quit,
+    /// A setting was changed, and must be applied and saved.
+    Changed(Setting),
+}
+
+impl Screen {
+    /// Returns the title drawn at the top of the screen.
+    pub fn title(self) -> &'static str {
+        match self {
+            Screen::Playing => "",
+            Screen::Menu => "Wordle",
+            Screen::Settings => "Settings",
+            Screen::Stats => "Statistics",
+            Screen::Help => "How to play",
+        }
+    }
+
+    /// Returns the rows of the screen, from top to bottom.
+    pub fn entries(self) -> &'static [Entry] {
+        match self {
+            Screen::Playing => &[],
+            Screen::Menu => &[
+                Entry::Play,
+                Entry::Open(Screen::Settings),
+                Entry::Open(Screen::Stats),
+                Entry::Open(Screen::Help),
+                Entry::Quit,
+            ],
+            Screen::Settings => &[
+                Entry::Setting(Setting::Mode),
+                Entry::Setting(Setting::Hard),
+                Entry::Setting(Setting::Theme),
+                Entry::Setting(Setting::Layout),
+                Entry::Setting(Setting::ReducedMotion),
+                Entry::Back,
+            ],
+            Screen::Stats | Screen::Help => &[Entry::Back],
+        }
+    }
+}
+
+impl Entry {
+    pub fn label(self) -> &'static str {
+        match self {
+            Entry::Play => "Play",
+            Entry::Open(screen) => screen.title(),
+            Entry::Quit => "Quit",
+            Entry::Setting(setting) => setting.label(),
+            Entry::Back => "Back",
+        }
+    }
+}
+
+impl Setting {
+    pub fn label(self) -> &'static str {
+        match self {
+            Setting::Mode => "Mode",
+            Setting::Hard => "Hard mode",
+            Setting::Theme => "Theme",
+            Setting::Layout => "Keyboard",
+            Setting::ReducedMotion => "Reduced motion",
+        }
+    }
+
+    /// Returns the name of the setting in the configuration file.
+    pub fn key(self) -> &'static str {
+        match self {
+            Setting::Mode => "mode",
+            Setting::Hard => "hard",
+            Setting::Theme => "theme",
+            Setting::Layout => "layout",
+            Setting::ReducedMotion => "reduced-motion",
+        }
+    }
+
+    /// Returns the value of the setting in `config`, as shown to the player.
+    pub fn value(self, config: &Config) -> String {
+        let on_off = |yes: bool| if yes { "on" } else { "off" };
+        match self {
+            Setting::Mode => config.get("mode").unwrap_or_default(),
+            Setting::Hard => on_off(config.rules.hard).into(),
+            Setting::Theme => config.theme.name.into(),
+            Setting::Layout => config.get("layout").unwrap_or_default(),
+            Setting::ReducedMotion => on_off(config.reduced_motion).into(),
+        }
+    }
+
+    /// Changes the setting in `config` to the next value, or to the previous
+    /// one when `step` is negative.
+    pub fn change(self, config: &mut Config, step: isize) {
+        // Moves `step` places from `index`, in a list of `len` values.
+        let cycle = |index: usize, len: usize| (index as isize + step).rem_euclid(len as isize);
+
+        match self {
+            Setting::Mode => {
+                config.mode = match config.mode {
+                    Mode::Random => Mode::Daily,
+                    Mode::Daily => Mode::Random,
+                }
+            }
+            Setting::Hard => config.rules.hard = !config.rules.hard,
+            Setting::Theme => {
+                let index = THEMES
+                    .iter()
+                    .position(|&theme| std::ptr::eq(theme, config.theme));
+                config.theme = THEMES[cycle(index.unwrap_or(0), THEMES.len()) as usize];
+            }
+            Setting::Layout => {
+                // The automatic layout comes before the others.
+                let index = match config.layout {
+                    Some(layout) => LAYOUTS
+                        .iter()
+                        .position(|&l| std::ptr::eq(l, layout))
+                        .map_or(0, |i| i + 1),
+                    None => 0,
+                };
+                config.layout = match cycle(index, LAYOUTS.len() + 1) as usize {
+                    0 => None,
+                    i => Some(LAYOUTS[i - 1]),
+                };
+            }
+            Setting::ReducedMotion => config.reduced_motion = !config.reduced_motion,
+        }
+    }
+}
+
+/// The screen being shown, and the settings it edits.
+pub struct Menu {
+    pub screen: Screen,
+    /// The index of the selected row of the screen.
+    pub selected: usize,
+    /// The settings of the game, as changed from the settings screen.
+    pub config: Config,
+}
+
+impl Menu {
+    /// Creates a menu showing the game.
+    pub fn new(config: Config) -> Self {
+        Self {
+            screen: Screen::Playing,
+            selected: 0,
+            config,
+        }
+    }
+
+    /// Shows `screen`, with its first row selected.
+    pub fn open(&mut self, screen: Screen) {
+        self.screen = screen;
+        self.selected = 0;
+    }
+
+    /// Handles a key pressed while a screen other than the game is shown.
+    pub fn key_press(&mut self, keycode: KeyCode) -> Option<Command> {
+        let len = self.screen.entries().len();
+        match keycode {
+            KeyCode::ESCAPE if self.screen == Screen::Menu => self.open(Screen::Playing),
+            KeyCode::ESCAPE | KeyCode::BACKSPACE if self.screen != Screen::Menu => {
+                self.open(Screen::Menu)
+            }
+            KeyCode::UP => self.selected = (self.selected + len - 1) % len,
+            KeyCode::DOWN | KeyCode::TAB => self.selected = (self.selected + 1) % len,
+            KeyCode::LEFT => return self.change(-1),
+            KeyCode::RIGHT => return self.change(1),
+            KeyCode::RETURN | KeyCode::KP_ENTER | KeyCode::SPACE => {
+                return self.activate(self.selected)
+            }
+            _ => (),
+        }
+
+        None
+    }
+
+    /// Changes the selected setting, if any.
+    fn change(&mut self, step: isize) -> Option<Command> {
+        match self.screen.entries().get(self.selected) {
+            Some(&Entry::Setting(setting)) => {
+                setting.change(&mut self.config, step);
+                Some(Command::Changed(setting))
+            }
+            _ => None,
+        }
+    }
+
+    /// Activates the row at `index` of the current screen.
+    pub fn activate(&mut self, index: usize) -> Option<Command> {
+        let entry = *self.screen.entries().get(index)?;
+        self.selected = index;
+        match entry {
+            Entry::Play => self.open(Screen::Playing),
+            Entry::Open(screen) => self.open(screen),
+            Entry::Quit => return Some(Command::Quit),
+            Entry::Setting(_) => return self.change(1),
+            Entry::Back => self.open(Screen::Menu),
+        }
+
+        None
+    }
+}
@@ -0,0 +1,118 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// The environment variable that may be set to the directory containing the
+/// data files of the game.
+pub const DATA_DIR_VAR: &str = "WORDLE_DATA_DIR";
+
+/// The error returned when a data file could not be found.
+#[derive(Debug)]
+pub struct NotFound {
+    /// The name of the file that was looked for.
+    pub name: PathBuf,
+    /// Every path that was tried, in order.
+    pub tried: Vec<PathBuf>,
+}
+
+impl fmt::Display for NotFound {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "could not find '{}'. Tried:", self.name.display())?;
+        for path in &self.tried {
+            write!(f, "\n  - {}", path.display())?;
+        }
+        write!(
+            f,
+            "\nSet {} to the directory containing it, or pass its path explicitly.",
+            DATA_DIR_VAR
+        )
+    }
+}
+
+/// Finds the data files of the game (word lists, images) regardless of the
+/// current working directory.
+pub struct Locator {
+    /// The directories that are searched, in order.
+    dirs: Vec<PathBuf>,
+}
+
+impl Locator {
+    /// Creates a new [`Locator`] searching, in order:
+    ///
+    /// 1. The directory named by `WORDLE_DATA_DIR`.
+    /// 2. The directory of the executable, and `../share/wordle` relative to it.
+    /// 3. `$XDG_DATA_HOME/wordle` (`~/.local/share/wordle` by default).
+    /// 4. Each `wordle` directory of `$XDG_DATA_DIRS` (`/usr/local/share:/usr/share`
+    ///    by default).
+    /// 5. The `share/wordle` directory of the `WORDLE_PREFIX` given at compile
+    ///    time (`/usr/local` by default).
+    /// 6. In debug builds, the source directory, so that the game can be run
+    ///    with `cargo run` from anywhere.
+    pub fn new() -> Self {
+        let mut dirs = Vec::new();
+
+        if let Some(dir) = non_empty_var(DATA_DIR_VAR) {
+            dirs.push(PathBuf::from(dir));
+        }
+
+        if let Some(exe_dir) = std::env::current_exe()
+            .ok()
+            .and_then(|exe| exe.parent().map(Path::to_path_buf))
+        {
+            dirs.push(exe_dir.clone());
+            dirs.push(exe_dir.join("../share/wordle"));
+        }
+
+        match non_empty_var("XDG_DATA_HOME") {
+            Some(dir) => dirs.push(Path::new(&dir).join("wordle")),
+            None => {
+                if let Some(home) = non_empty_var("HOME") {
+                    dirs.push(Path::new(&home).join(".local/share/wordle"));
+                }
+            }
+        }
+
+        let data_dirs =
+            non_empty_var("XDG_DATA_DIRS").unwrap_or_else(|| "/usr/local/share:/usr/share".into());
+        for dir in std::env::split_paths(&data_dirs) {
+            if !dir.as_os_str().is_empty() {
+                dirs.push(dir.join("wordle"));
+            }
+        }
+
+        let prefix = option_env!("WORDLE_PREFIX").unwrap_or("/usr/local");
+        let dir = Path::new(prefix).join("share/wordle");
+        // The default prefix is usually one of the XDG data directories.
+        if !dirs.contains(&dir) {
+            dirs.push(dir);
+        }
+
+        #[cfg(debug_assertions)]
+        dirs.push(PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+
+        Self { dirs }
+    }
+
+    /// Finds the file or directory named `name` in the first directory that
+    /// contains it.
+    pub fn find(&self, name: impl AsRef<Path>) -> Result<PathBuf, NotFound> {
+        let name = name.as_ref();
+        let mut tried = Vec::with_capacity(self.dirs.len());
+
+        for dir in &self.dirs {
+            let path = dir.join(name);
+            if path.exists() {
+                return Ok(path);
+            }
+            tried.push(path);
+        }
+
+        Err(NotFound {
+            name: name.into(),
+            tried,
+        })
+    }
+}
+
+fn non_empty_var(name: &str) -> Option<std::ffi::OsString> {
+    std::env::var_os(name).filter(|value| !value.is_empty())
+}
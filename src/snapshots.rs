@@ -0,0 +1,209 @@
+//! Renders known states of the game without a display, and compares them to
+//! the reference images of `tests/snapshots`.
+//!
+//! After an intended change of the rendering, run the tests with
+//! `UPDATE_SNAPSHOTS=1` to write the references again. When an image does not
+//! match its reference, the rendered image and a diff showing the pixels that
+//! differ in red are written to `target/snapshots`.
+
+use std::path::{Path, PathBuf};
+
+use mlx::Color;
+
+use crate::export;
+use crate::*;
+
+/// The largest difference of a channel for two pixels to be considered equal.
+const CHANNEL_TOLERANCE: u8 = 3;
+/// The largest number of pixels that may differ from the reference, for every
+/// 10000 pixels of the image.
+const DIFFERENT_PIXELS_PER_10000: usize = 5;
+
+/// The words accepted as guesses by the games of the tests.
+const WORDS: [&str; 10] = [
+    "CRANE", "CRONE", "SLATE", "PIOUS", "BUDGY", "FIGHT", "WHOMP", "LYMPH", "TRICK", "NAVEL",
+];
+
+fn word(s: &str) -> Word {
+    s.chars().map(|c| ENGLISH.letter(c).unwrap()).collect()
+}
+
+/// Creates a game of the default rules whose winning word is `winning`, and
+/// plays the `guesses`.
+fn game(winning: &str, guesses: &[&str]) -> Game {
+    let words = WORDS.iter().map(|w| word(w)).collect();
+    let mut game = Game::new(Rules::default(), &ENGLISH, words, vec![word(winning)]);
+    for guess in guesses {
+        for letter in word(guess).iter() {
+            game.type_letter(*letter);
+        }
+        game.confirm_word();
+    }
+    game
+}
+
+/// Draws `game` with `theme`, at half the size of the images.
+fn render(game: &Game, theme: &'static Theme) -> Framebuffer {
+    let config = Config {
+        theme,
+        reduced_motion: true,
+        ..Config::default()
+    };
+    let (width, height) = window_size(&game.rules, 0.5);
+    let view = View::new(&config, width, height);
+
+    let frontend = Headless::new(width, height);
+    let assets = AssetSource::Dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("assets"));
+    let images = Images::load(&frontend, &assets)
+        .unwrap()
+        .themed(&frontend, theme);
+
+    let mut canvas = frontend.create_canvas(width, height);
+    draw(game, &view, &mut canvas, &images);
+    canvas
+}
+
+/// Returns whether two pixels are equal, within [`CHANNEL_TOLERANCE`].
+fn similar(a: Color, b: Color) -> bool {
+    [(a.r, b.r), (a.g, b.g), (a.b, b.b)]
+        .into_iter()
+        .all(|(a, b)| a.abs_diff(b) <= CHANNEL_TOLERANCE)
+}
+
+/// Compares `image` to the reference image `name`.
+///
+/// ## Panics
+///
+/// This function panics if the images differ, or if there is no reference.
+fn check(name: &str, image: &Framebuffer) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let reference_path = root.join("tests/snapshots").join(format!("{}.png", name));
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::create_dir_all(reference_path.parent().unwrap()).unwrap();
+        std::fs::write(&reference_path, export::png(image)).unwrap();
+        return;
+    }
+
+    let output = |suffix: &str, image: &Framebuffer| -> PathBuf {
+        let dir = root.join("target/snapshots");
+        let path = dir.join(format!("{}.{}.png", name, suffix));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(&path, export::png(image)).unwrap();
+        path
+    };
+
+    let reference = match std::fs::read(&reference_path) {
+        Ok(data) => crate::decode::image(&data)
+            .unwrap_or_else(|err| panic!("{}: {}", reference_path.display(), err)),
+        Err(err) => panic!(
+            "cannot read '{}': {} (run with UPDATE_SNAPSHOTS=1 to create it); \
+             the rendered image is '{}'",
+            reference_path.display(),
+            err,
+            output("actual", image).display(),
+        ),
+    };
+
+    let (width, height) = (reference.width(), reference.height());
+    if (image.width(), image.height()) != (width, height) {
+        panic!(
+            "'{}' is {}x{}, but the rendered image '{}' is {}x{}",
+            reference_path.display(),
+            width,
+            height,
+            output("actual", image).display(),
+            image.width(),
+            image.height(),
+        );
+    }
+
+    // The diff shows the reference faded, and the pixels that differ in red.
+    let mut diff = Framebuffer::new(width, height);
+    let mut different = 0;
+    for y in 0..height {
+        for x in 0..width {
+            let expected = reference.get_pixel(x, y).unwrap();
+            if similar(image.get_pixel(x, y).unwrap(), expected) {
+                diff.set_pixel(x, y, expected.mix(Color::WHITE, 0.75));
+            } else {
+                diff.set_pixel(x, y, Color::rgb(0xff0000));
+                different += 1;
+            }
+        }
+    }
+
+    let allowed = (width * height) as usize * DIFFERENT_PIXELS_PER_10000 / 10000;
+    if different > allowed {
+        panic!(
+            "{} pixels differ from '{}' ({} allowed): see '{}' and '{}'",
+            different,
+            reference_path.display(),
+            allowed,
+            output("actual", image).display(),
+            output("diff", &diff).display(),
+        );
+    }
+}
+
+#[test]
+fn mid_game() {
+    let game = game("CRANE", &["SLATE", "CRONE"]);
+    for theme in THEMES {
+        check(&format!("mid_game_{}", theme.name), &render(&game, theme));
+    }
+}
+
+#[test]
+fn typing() {
+    let mut game = game("CRANE", &["PIOUS"]);
+    for letter in word("TRI").iter() {
+        game.type_letter(*letter);
+    }
+    check("typing", &render(&game, &LIGHT));
+}
+
+#[test]
+fn won() {
+    let game = game("CRANE", &["SLATE", "CRONE", "CRANE"]);
+    check("won", &render(&game, &LIGHT));
+}
+
+#[test]
+fn lost() {
+    let guesses = ["SLATE", "PIOUS", "BUDGY", "FIGHT", "WHOMP", "LYMPH"];
+    let game = game("CRANE", &guesses);
+    check("lost", &render(&game, &LIGHT));
+}
+
+/// Plays a game with its animations, and checks that drawing only what
+/// changed shows the same as drawing the whole game, frame after frame.
+#[test]
+fn partial_redraws() {
+    let config = Config {
+        reduced_motion: false,
+        ..Config::default()
+    };
+    let game = game("CRANE", &[]);
+    let (width, height) = window_size(&game.rules, 0.5);
+    let view = View::new(&config, width, height);
+
+    let mut frontend = Headless::new(width, height);
+    let assets = AssetSource::Dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("assets"));
+    let sprites = Images::load(&frontend, &assets).unwrap();
+    let mut app = App::new(&frontend, game, view, sprites);
+
+    // An incomplete word is shaken and erased, then the game is won.
+    for event in key_events(b"cr\r\x7f\x7fcrane\r") {
+        frontend.push(event);
+        loop {
+            app.frame(&mut frontend);
+            let (expected, drawn) = app.draw_all();
+            assert!(*drawn == expected, "the frame differs from the whole game");
+            if !app.is_animating() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+    }
+}
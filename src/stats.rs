@@ -0,0 +1,57 @@
+//! Statistics of the games played since the game was started.
+
+use crate::game::{Game, GameState};
+
+/// The results of the games played so far.
+#[derive(Clone, Debug)]
+pub struct Stats {
+    /// The number of finished games.
+    pub played: u32,
+    /// The number of games that were won.
+    pub won: u32,
+    /// The number of games won in a row, up to the last one.
+    pub streak: u32,
+    /// The longest sequence of games won in a row.
+    pub best_streak: u32,
+    /// The number of games won in each number of tries, starting at one.
+    pub distribution: Vec<u32>,
+}
+
+impl Stats {
+    /// Creates empty statistics for games of at most `max_tries` tries.
+    pub fn new(max_tries: usize) -> Self {
+        Self {
+            played: 0,
+            won: 0,
+            streak: 0,
+            best_streak: 0,
+            distribution: vec![0; max_tries],
+        }
+    }
+
+    /// Records the result of a finished game.
+    pub fn record(&mut self, game: &Game) {
+        match game.state {
+            GameState::Playing => return,
+            GameState::Won => {
+                self.won += 1;
+                self.streak += 1;
+                self.best_streak = u32::max(self.best_streak, self.streak);
+                if let Some(count) = self.distribution.get_mut(game.current_try - 1) {
+                    *count += 1;
+                }
+            }
+            GameState::Lost => self.streak = 0,
+        }
+
+        self.played += 1;
+    }
+
+    /// Returns the percentage of games that were won.
+    pub fn win_rate(&self) -> u32 {
+        match self.played {
+            0 => 0,
+            played => (self.won * 100 + played / 2) / played,
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! The colors the game is drawn with.
+
+use mlx::Color;
+
+/// A set of colors to draw the game with.
+#[derive(Debug)]
+pub struct Theme {
+    /// The name of the theme, as used in the configuration file.
+    pub name: &'static str,
+    /// The background of the window.
+    pub background: Color,
+    /// The outline of empty tiles.
+    pub outline: Color,
+    /// The background of tiles and keys that were not scored yet.
+    pub tile: Color,
+    /// The letters of tiles that were not scored yet, and the icons of keys.
+    pub ink: Color,
+    /// The letters of keys that were not scored yet.
+    pub key_ink: Color,
+    /// The border of keys.
+    pub key_border: Color,
+    /// The frame of the key under the mouse pointer.
+    pub hover: Color,
+    /// The background of letters in the right place.
+    pub correct: Color,
+    /// The background of letters in the wrong place.
+    pub misplaced: Color,
+    /// The background of letters that are not in the word.
+    pub incorrect: Color,
+    /// The letters of scored tiles and keys.
+    pub scored_ink: Color,
+}
+
+/// The colors of the images, as they were drawn.
+pub const LIGHT: Theme = Theme {
+    name: "light",
+    background: Color::rgb(0xffffff),
+    outline: Color::rgb(0xcacaca),
+    tile: Color::rgb(0xffffff),
+    ink: Color::rgb(0x3a3a3a),
+    key_ink: Color::rgb(0x9e9f9f),
+    key_border: Color::rgb(0xa3a4a4),
+    hover: Color::rgb(0x565656),
+    correct: Color::rgb(0x639459),
+    misplaced: Color::rgb(0xded248),
+    incorrect: Color::rgb(0x606262),
+    scored_ink: Color::rgb(0xffffff),
+};
+
+/// Light letters on a dark background.
+pub const DARK: Theme = Theme {
+    name: "dark",
+    background: Color::rgb(0x121213),
+    outline: Color::rgb(0x3a3a3c),
+    tile: Color::rgb(0x121213),
+    ink: Color::rgb(0xf8f8f8),
+    key_ink: Color::rgb(0xd7dadc),
+    key_border: Color::rgb(0x818384),
+    hover: Color::rgb(0xd7dadc),
+    correct: Color::rgb(0x538d4e),
+    misplaced: Color::rgb(0xb59f3b),
+    incorrect: Color::rgb(0x3a3a3c),
+    scored_ink: Color::rgb(0xf8f8f8),
+};
+
+/// Black on white, with dark colors behind scored letters.
+pub const HIGH_CONTRAST: Theme = Theme {
+    name: "high-contrast",
+    background: Color::rgb(0xffffff),
+    outline: Color::rgb(0x000000),
+    tile: Color::rgb(0xffffff),
+    ink: Color::rgb(0x000000),
+    key_ink: Color::rgb(0x000000),
+    key_border: Color::rgb(0x000000),
+    hover: Color::rgb(0x0050ff),
+    correct: Color::rgb(0x006400),
+    misplaced: Color::rgb(0x9c4a00),
+    incorrect: Color::rgb(0x3c3c3c),
+    scored_ink: Color::rgb(0xffffff),
+};
+
+/// Orange and blue, which remain distinct with most color vision
+/// deficiencies.
+pub const COLORBLIND: Theme = Theme {
+    name: "colorblind",
+    correct: Color::rgb(0xf5793a),
+    misplaced: Color::rgb(0x85c0f9),
+    ..LIGHT
+};
+
+/// Every available theme.
+pub const THEMES: [&Theme; 4] = [&LIGHT, &DARK, &HIGH_CONTRAST, &COLORBLIND];
+
+impl Theme {
+    /// Finds the theme named `name`.
+    pub fn from_name(name: &str) -> Option<&'static Theme> {
+        THEMES.into_iter().find(|theme| theme.name == name)
+    }
+}
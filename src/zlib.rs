@@ -0,0 +1,535 @@
+//! Compresses and decompresses the zlib streams of PNG images (RFC 1950 and
+//! RFC 1951), and computes the checksums zlib provides.
+
+/// The lengths of the length codes `257..=285`, before their extra bits.
+const LENGTH_BASES: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+/// The distances of the distance codes, before their extra bits.
+const DISTANCE_BASES: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+/// The size of the window in which repeated data is looked for.
+const WINDOW_SIZE: usize = 32768;
+/// The shortest and longest repetitions that can be encoded.
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+/// How many earlier positions are tried when looking for a repetition.
+const MAX_CHAIN: usize = 64;
+/// The order the lengths of the code length code are given in.
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
+/// The error returned when the decompressed data outgrows its expected size.
+const TOO_LONG: &str = "the decompressed data is longer than expected";
+
+/// Reads a stream one bit at a time, starting with the least significant bit
+/// of each byte.
+struct Bits<'a> {
+    data: &'a [u8],
+    /// The index of the next bit.
+    position: usize,
+}
+
+impl Bits<'_> {
+    fn bit(&mut self) -> Result<u32, &'static str> {
+        let byte = self
+            .data
+            .get(self.position / 8)
+            .ok_or("the compressed data is truncated")?;
+        let bit = (byte >> (self.position % 8)) & 1;
+        self.position += 1;
+        Ok(bit as u32)
+    }
+
+    /// Reads a number of `count` bits.
+    fn bits(&mut self, count: u8) -> Result<u32, &'static str> {
+        let mut value = 0;
+        for i in 0..count {
+            value |= self.bit()? << i;
+        }
+        Ok(value)
+    }
+
+    /// Skips the bits left in the current byte.
+    fn align(&mut self) {
+        self.position = self.position.div_ceil(8) * 8;
+    }
+}
+
+/// A canonical Huffman code.
+struct Huffman {
+    /// The number of codes of each length.
+    counts: [u16; 16],
+    /// The symbols, ordered by code.
+    symbols: Vec<u16>,
+}
+
+impl Huffman {
+    /// Builds the code given the length of the code of each symbol, `0` for
+    /// the symbols that are not used.
+    fn new(lengths: &[u8]) -> Result<Self, &'static str> {
+        let mut counts = [0u16; 16];
+        for &length in lengths {
+            counts[length as usize] += 1;
+        }
+        counts[0] = 0;
+
+        // There cannot be more codes of a length than it allows.
+        let mut left = 1i32;
+        for &count in &counts[1..] {
+            left = left * 2 - count as i32;
+            if left < 0 {
+                return Err("invalid Huffman code lengths");
+            }
+        }
+
+        let mut offsets = [0u16; 16];
+        for length in 1..15 {
+            offsets[length + 1] = offsets[length] + counts[length];
+        }
+        let mut symbols = vec![0; lengths.len()];
+        for (symbol, &length) in lengths.iter().enumerate() {
+            if length != 0 {
+                symbols[offsets[length as usize] as usize] = symbol as u16;
+                offsets[length as usize] += 1;
+            }
+        }
+
+        Ok(Self { counts, symbols })
+    }
+
+    /// Reads a symbol from `bits`.
+    fn decode(&self, bits: &mut Bits) -> Result<u16, &'static str> {
+        // The codes of a length follow the last code of the previous length,
+        // shifted by one bit.
+        let (mut code, mut first, mut index) = (0i32, 0i32, 0i32);
+        for &count in &self.counts[1..] {
+            code |= bits.bit()? as i32;
+            let count = count as i32;
+            if code - first < count {
+                return Ok(self.symbols[(index + code - first) as usize]);
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        Err("invalid Huffman code")
+    }
+}
+
+/// Reads the codes of a block compressed with dynamic Huffman codes.
+fn dynamic_codes(bits: &mut Bits) -> Result<(Huffman, Huffman), &'static str> {
+    let literals = bits.bits(5)? as usize + 257;
+    let distances = bits.bits(5)? as usize + 1;
+    let code_lengths = bits.bits(4)? as usize + 4;
+
+    let mut lengths = [0u8; 19];
+    for &symbol in &CODE_LENGTH_ORDER[..code_lengths] {
+        lengths[symbol] = bits.bits(3)? as u8;
+    }
+    let code_length_code = Huffman::new(&lengths)?;
+
+    let mut lengths = Vec::with_capacity(literals + distances);
+    while lengths.len() < literals + distances {
+        let symbol = code_length_code.decode(bits)?;
+        let (length, repeat) = match symbol {
+            0..=15 => (symbol as u8, 1),
+            16 => {
+                let previous = *lengths
+                    .last()
+                    .ok_or("repeated length without a previous one")?;
+                (previous, 3 + bits.bits(2)?)
+            }
+            17 => (0, 3 + bits.bits(3)?),
+            _ => (0, 11 + bits.bits(7)?),
+        };
+        if lengths.len() + repeat as usize > literals + distances {
+            return Err("too many code lengths");
+        }
+        lengths.extend(std::iter::repeat_n(length, repeat as usize));
+    }
+    if lengths[256] == 0 {
+        return Err("no code for the end of a block");
+    }
+
+    Ok((
+        Huffman::new(&lengths[..literals])?,
+        Huffman::new(&lengths[literals..])?,
+    ))
+}
+
+/// Returns the codes of the blocks compressed with fixed Huffman codes.
+fn fixed_codes() -> (Huffman, Huffman) {
+    let mut lengths = [0u8; 288];
+    lengths[..144].fill(8);
+    lengths[144..256].fill(9);
+    lengths[256..280].fill(7);
+    lengths[280..].fill(8);
+
+    let literals = Huffman::new(&lengths).unwrap();
+    let distances = Huffman::new(&[5; 30]).unwrap();
+    (literals, distances)
+}
+
+/// Decompresses a block compressed with `literals` and `distances`, appending
+/// it to `output`, which may not grow beyond `max_len` bytes.
+fn codes(
+    bits: &mut Bits,
+    output: &mut Vec<u8>,
+    max_len: usize,
+    literals: &Huffman,
+    distances: &Huffman,
+) -> Result<(), &'static str> {
+    loop {
+        let symbol = literals.decode(bits)? as usize;
+        match symbol {
+            0..=255 if output.len() >= max_len => return Err(TOO_LONG),
+            0..=255 => output.push(symbol as u8),
+            256 => return Ok(()),
+            _ => {
+                let i = symbol - 257;
+                let base = *LENGTH_BASES.get(i).ok_or("invalid length code")?;
+                let length = base as usize + bits.bits(LENGTH_EXTRA[i])? as usize;
+
+                let i = distances.decode(bits)? as usize;
+                let base = *DISTANCE_BASES.get(i).ok_or("invalid distance code")?;
+                let distance = base as usize + bits.bits(DISTANCE_EXTRA[i])? as usize;
+                if distance > output.len() {
+                    return Err("a distance goes back before the start of the data");
+                }
+                if length > max_len - output.len() {
+                    return Err(TOO_LONG);
+                }
+
+                // The copy may overlap what it appends.
+                let start = output.len() - distance;
+                for i in 0..length {
+                    output.push(output[start + i]);
+                }
+            }
+        }
+    }
+}
+
+/// Returns the Adler-32 checksum of `data`.
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+/// Returns the CRC-32 checksum of `data`, as computed for the chunks of PNG
+/// images.
+pub fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xedb88320 & (crc & 1).wrapping_neg());
+        }
+    }
+    !crc
+}
+
+/// Decompresses the zlib stream `data`, failing as soon as the decompressed
+/// data is longer than `max_len` bytes.
+pub fn decompress(data: &[u8], max_len: usize) -> Result<Vec<u8>, &'static str> {
+    let [method, flags, ..] = *data else {
+        return Err("the compressed data is truncated");
+    };
+    if method & 0x0f != 8 || !(method as u16 * 256 + flags as u16).is_multiple_of(31) {
+        return Err("invalid zlib header");
+    }
+    if flags & 0x20 != 0 {
+        return Err("preset dictionaries are not supported");
+    }
+
+    let mut bits = Bits { data, position: 16 };
+    let mut output = Vec::new();
+    loop {
+        let last = bits.bit()? == 1;
+        match bits.bits(2)? {
+            0 => {
+                bits.align();
+                let start = bits.position / 8;
+                let header = data
+                    .get(start..start + 4)
+                    .ok_or("the compressed data is truncated")?;
+                let len = u16::from_le_bytes([header[0], header[1]]);
+                let complement = u16::from_le_bytes([header[2], header[3]]);
+                if len != !complement {
+                    return Err("invalid length of a stored block");
+                }
+                let block = data
+                    .get(start + 4..start + 4 + len as usize)
+                    .ok_or("the compressed data is truncated")?;
+                if block.len() > max_len - output.len() {
+                    return Err(TOO_LONG);
+                }
+                output.extend_from_slice(block);
+                bits.position = (start + 4 + len as usize) * 8;
+            }
+            1 => {
+                let (literals, distances) = fixed_codes();
+                codes(&mut bits, &mut output, max_len, &literals, &distances)?;
+            }
+            2 => {
+                let (literals, distances) = dynamic_codes(&mut bits)?;
+                codes(&mut bits, &mut output, max_len, &literals, &distances)?;
+            }
+            _ => return Err("invalid block type"),
+        }
+        if last {
+            break;
+        }
+    }
+
+    bits.align();
+    let start = bits.position / 8;
+    let checksum = data
+        .get(start..start + 4)
+        .ok_or("the compressed data is truncated")?;
+    if u32::from_be_bytes(checksum.try_into().unwrap()) != adler32(&output) {
+        return Err("the checksum of the decompressed data does not match");
+    }
+
+    Ok(output)
+}
+
+/// Writes a stream one bit at a time, starting with the least significant bit
+/// of each byte.
+struct BitWriter {
+    output: Vec<u8>,
+    /// The bits not written to `output` yet.
+    pending: u64,
+    count: u32,
+}
+
+impl BitWriter {
+    /// Writes the `count` lowest bits of `value`.
+    fn bits(&mut self, value: u32, count: u32) {
+        self.pending |= (value as u64) << self.count;
+        self.count += count;
+        while self.count >= 8 {
+            self.output.push(self.pending as u8);
+            self.pending >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    /// Writes the Huffman code `code` of `len` bits, which are stored
+    /// starting with the most significant one.
+    fn code(&mut self, code: u32, len: u32) {
+        self.bits(code.reverse_bits() >> (32 - len), len);
+    }
+
+    /// Writes the symbol `symbol` of the fixed literal code.
+    fn literal(&mut self, symbol: u32) {
+        match symbol {
+            0..=143 => self.code(0x30 + symbol, 8),
+            144..=255 => self.code(0x190 + symbol - 144, 9),
+            256..=279 => self.code(symbol - 256, 7),
+            _ => self.code(0xc0 + symbol - 280, 8),
+        }
+    }
+
+    /// Writes a repetition of `length` bytes found `distance` bytes back.
+    fn repetition(&mut self, length: usize, distance: usize) {
+        let i = LENGTH_BASES.partition_point(|&base| base as usize <= length) - 1;
+        self.literal(257 + i as u32);
+        self.bits(
+            (length - LENGTH_BASES[i] as usize) as u32,
+            LENGTH_EXTRA[i] as u32,
+        );
+
+        let i = DISTANCE_BASES.partition_point(|&base| base as usize <= distance) - 1;
+        self.code(i as u32, 5);
+        self.bits(
+            (distance - DISTANCE_BASES[i] as usize) as u32,
+            DISTANCE_EXTRA[i] as u32,
+        );
+    }
+
+    /// Writes the bits left, padding the last byte with zeros.
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.output.push(self.pending as u8);
+        }
+        self.output
+    }
+}
+
+/// Compresses `data` into a zlib stream.
+///
+/// The data is written as a single block, with the fixed Huffman codes, which
+/// is enough for the large areas of a single color the game is made of.
+pub fn compress(data: &[u8]) -> Vec<u8> {
+    let mut writer = BitWriter {
+        output: vec![0x78, 0x01],
+        pending: 0,
+        count: 0,
+    };
+    // The last block, compressed with fixed Huffman codes.
+    writer.bits(1, 1);
+    writer.bits(1, 2);
+
+    // The last position of each hash of 3 bytes, and for each position, the
+    // previous one with the same hash.
+    let hash = |i: usize| {
+        ((data[i] as usize) << 10 ^ (data[i + 1] as usize) << 5 ^ data[i + 2] as usize) & 0x7fff
+    };
+    let mut heads = vec![usize::MAX; 0x8000];
+    let mut previous = vec![usize::MAX; data.len()];
+
+    let mut i = 0;
+    while i < data.len() {
+        let (mut length, mut distance) = (0, 0);
+        if i + MIN_MATCH <= data.len() {
+            let max = usize::min(MAX_MATCH, data.len() - i);
+            let mut candidate = heads[hash(i)];
+            for _ in 0..MAX_CHAIN {
+                if candidate == usize::MAX || i - candidate > WINDOW_SIZE {
+                    break;
+                }
+                let len = data[candidate..]
+                    .iter()
+                    .zip(&data[i..i + max])
+                    .take_while(|(a, b)| a == b)
+                    .count();
+                if len > length {
+                    (length, distance) = (len, i - candidate);
+                    if len == max {
+                        break;
+                    }
+                }
+                candidate = previous[candidate];
+            }
+        }
+
+        let step = if length >= MIN_MATCH {
+            writer.repetition(length, distance);
+            length
+        } else {
+            writer.literal(data[i] as u32);
+            1
+        };
+        // Only the positions followed by enough bytes for a hash are kept.
+        let end = usize::min(i + step, data.len().saturating_sub(MIN_MATCH - 1)).max(i);
+        for (j, link) in (i..end).zip(&mut previous[i..end]) {
+            let h = hash(j);
+            *link = heads[h];
+            heads[h] = j;
+        }
+        i += step;
+    }
+    writer.literal(256);
+
+    let mut output = writer.finish();
+    output.extend(adler32(data).to_be_bytes());
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAX: usize = usize::MAX;
+
+    const TEXT: &[u8] = b"eieeeaaasteeaettnerae eieteeesoethet eaeaaoeteheatetosees te t \
+        ratahettiaatieae tthetorh teahaeeatta eaetrniitseeaeeeooetatitaehon et aeaoeteatio\
+        eatattteoonntaneeseoieeoeeheeeaeeeeenetteeeeaehesrtarta";
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    /// Streams written by Python's `zlib.compress`.
+    #[test]
+    fn decompress_blocks() {
+        let short = b"abcdefghij".repeat(3);
+        let short = [&short[..], &(0..40).collect::<Vec<u8>>()].concat();
+
+        let stored = hex(
+            "7801014600b9ff6162636465666768696a6162636465666768696a6162636465666768696a00010203\
+             0405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f2021222324252627bd360ef2",
+        );
+        assert_eq!(decompress(&stored, MAX).unwrap(), short);
+
+        let fixed = hex(
+            "78da4b4c4a4e494d4bcfc8cc4ac4c26260646266616563e7e0e4e2e6e1e5e3171014121611151397909\
+             4929691959357505452565155530700bd360ef2",
+        );
+        assert_eq!(decompress(&fixed, MAX).unwrap(), short);
+
+        let dynamic = hex(
+            "78da258e4b0a44210c04af92abf5a240370968df9fd7ce80a8b13f251b90749d03bb39a2d864e60e5eb8\
+             a248b9b390f1dc4865ca7564c5e12d79c7567e81392bb296d269ebe57d7a6fdf07814957fc166bba0248\
+             ffabcfdb644bc6f1745b1d12933f0e81ffd3d021f29b16f758591ffcb34fa1",
+        );
+        assert_eq!(decompress(&dynamic, MAX).unwrap(), TEXT);
+    }
+
+    #[test]
+    fn round_trip() {
+        let repeated = b"wordle ".repeat(10000);
+        let noise: Vec<u8> = (0..70000u64).map(|i| (i * i % 251) as u8).collect();
+        for data in [&[][..], b"a", TEXT, &repeated, &noise] {
+            assert_eq!(decompress(&compress(data), MAX).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn corrupt() {
+        let data = compress(TEXT);
+        for len in 0..data.len() {
+            assert!(decompress(&data[..len], MAX).is_err(), "{} bytes", len);
+        }
+
+        let mut checksum = data.clone();
+        *checksum.last_mut().unwrap() ^= 1;
+        assert!(decompress(&checksum, MAX).is_err());
+
+        let mut header = data.clone();
+        header[0] = 0x79;
+        assert_eq!(decompress(&header, MAX), Err("invalid zlib header"));
+
+        // A block of type 3 does not exist.
+        assert!(decompress(&[0x78, 0x01, 0x07], MAX).is_err());
+    }
+
+    #[test]
+    fn too_long() {
+        let repeated = b"wordle ".repeat(10000);
+        let stored = hex(
+            "7801014600b9ff6162636465666768696a6162636465666768696a6162636465666768696a00010203\
+             0405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f2021222324252627bd360ef2",
+        );
+        for data in [compress(TEXT), compress(&repeated), stored] {
+            let len = decompress(&data, MAX).unwrap().len();
+            assert!(decompress(&data, len).is_ok());
+            assert_eq!(decompress(&data, len - 1), Err(TOO_LONG));
+        }
+    }
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e60398);
+    }
+}